@@ -0,0 +1,37 @@
+use crate::entity::Entity;
+
+/// A species of the medium added to complete the equation before balancing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddedSpecies {
+    /// Entity
+    pub entity: Entity,
+    /// Whether the species is added to the reactants
+    pub is_reactant: bool,
+}
+
+impl AddedSpecies {
+    /// AddedSpecies constructor.
+    pub fn new(entity: Entity, is_reactant: bool) -> Self {
+        Self { entity, is_reactant }
+    }
+
+    /// Returns the description.
+    pub fn get_description(&self) -> String {
+        format!(
+            "{} is added to the {}.",
+            self.entity.format(),
+            if self.is_reactant { "reactants" } else { "products" },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::added_species::AddedSpecies;
+
+    #[test]
+    fn test_get_description() {
+        let added_species = AddedSpecies::new("H2O".parse().unwrap(), false);
+        assert_eq!(added_species.get_description(), "H2O is added to the products.");
+    }
+}
@@ -0,0 +1,638 @@
+use std::ops::Range;
+use itertools::Itertools;
+use num::{integer::lcm, rational::Ratio, One, Signed, Zero};
+use crate::{
+    algebraic_step::AlgebraicStep,
+    balancer::Balancer,
+    balancer_error::BalancerError,
+    entity::Entity,
+    equation::Equation,
+};
+
+/// A linear expression: the counts of the variables followed by the constant.
+type Expression = Vec<Ratio<i32>>;
+
+/// The steps of a solution with the values of the variables.
+type Solution = (Vec<AlgebraicStep>, Vec<Ratio<i32>>);
+
+/// A worked solution of the algebraic method: a letter for every coefficient, an equation for
+/// every element and the charge, then the substitutions, the eliminations and the integer
+/// scaling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlgebraicSolution {
+    /// Equation
+    pub equation: Equation,
+    /// Equations by the names of the elements and "charge", as the counts of the variables,
+    /// positive for the reactants and negative for the products
+    pub equations: Vec<(String, Vec<i32>)>,
+    /// Steps
+    pub steps: Vec<AlgebraicStep>,
+    /// Coefficients of the reactants followed by the products
+    pub coefficients: Vec<i32>,
+}
+
+impl AlgebraicSolution {
+    /// Solves the equations of the elements and the charge, setting the first variable that
+    /// can be nonzero to 1.
+    pub fn new(equation: &Equation) -> Result<Self, BalancerError> {
+        let equations = Self::get_equations(equation);
+        let variables_count = equation.reactants.len() + equation.products.len();
+        let (mut steps, values) = (0..variables_count)
+            .map(|i| Self::solve(&equations, variables_count, i))
+            .find_map(Result::transpose)
+            .unwrap_or(Err(BalancerError::AllCoefficientsAreZero))?;
+        let mut factor = values.iter().fold(1, |result, x| lcm(result, *x.denom()));
+        let mut coefficients: Vec<i32> = values.iter()
+            .map(|x| (x * factor).to_integer())
+            .collect();
+        let negative_count = coefficients.iter().filter(|&&x| x < 0).count();
+        let positive_count = coefficients.iter().filter(|&&x| x > 0).count();
+
+        if negative_count > positive_count {
+            factor = -factor;
+            coefficients.iter_mut().for_each(|x| *x = -*x);
+        }
+
+        if coefficients.iter().any(|&x| x < 0) {
+            return Err(BalancerError::ReactionIsImpossibleInThisDirection {
+                names: equation.reactants.iter()
+                    .chain(&equation.products)
+                    .zip(&coefficients)
+                    .filter(|(_, &x)| x < 0)
+                    .map(|(x, _)| x.format())
+                    .collect(),
+            });
+        }
+
+        steps.push(AlgebraicStep::Scaling { factor, coefficients: coefficients.clone() });
+
+        Ok(Self { equation: equation.clone(), equations, steps, coefficients })
+    }
+
+    /// Solves the equations with the variable set to 1, returning the steps and the values,
+    /// or nothing if the equations contradict it.
+    fn solve(
+        equations: &[(String, Vec<i32>)],
+        variables_count: usize,
+        assumed_index: usize,
+    ) -> Result<Option<Solution>, BalancerError> {
+        let mut expressions: Vec<Option<Expression>> = vec![None; variables_count];
+        let mut steps = vec![AlgebraicStep::Assumption { variable_index: assumed_index }];
+        let mut eliminated_indices = vec![];
+
+        Self::assign(
+            &mut expressions,
+            assumed_index,
+            Self::get_constant_expression(variables_count, Ratio::one()),
+        );
+
+        loop {
+            let forms: Vec<Expression> = equations.iter()
+                .map(|(_, counts)| Self::resolve(counts, 0..variables_count, 1, &expressions))
+                .collect();
+            let unknowns: Vec<Vec<usize>> = forms.iter()
+                .map(|x| (0..variables_count).filter(|&j| !x[j].is_zero()).collect())
+                .collect();
+
+            if forms.iter().zip(&unknowns)
+                .any(|(x, y)| y.is_empty() && !x[variables_count].is_zero()) {
+                return Ok(None);
+            }
+
+            if let Some((equation_index, variable_index)) = unknowns.iter().enumerate()
+                .find_map(|(i, x)| if let [j] = x[..] { Some((i, j)) } else { None }) {
+                let form = &forms[equation_index];
+                let value = -form[variables_count] / form[variable_index];
+
+                steps.push(AlgebraicStep::Substitution { equation_index, variable_index, value });
+                Self::assign(
+                    &mut expressions,
+                    variable_index,
+                    Self::get_constant_expression(variables_count, value),
+                );
+
+                continue;
+            }
+
+            let Some(equation_index) = (0..unknowns.len())
+                .filter(|&i| !unknowns[i].is_empty())
+                .min_by_key(|&i| unknowns[i].len()) else {
+                break;
+            };
+            let form = &forms[equation_index];
+            let variable_index = unknowns[equation_index].iter()
+                .copied()
+                .min_by_key(|&j| form[j].abs())
+                .expect("The equation has unknown variables.");
+            let mut expression: Expression = form.iter()
+                .map(|x| -x / form[variable_index])
+                .collect();
+
+            expression[variable_index] = Ratio::zero();
+            steps.push(AlgebraicStep::Elimination {
+                equation_index,
+                variable_index,
+                terms: (0..variables_count)
+                    .filter(|&j| !expression[j].is_zero())
+                    .map(|j| (expression[j], j))
+                    .collect(),
+                constant: expression[variables_count],
+            });
+            eliminated_indices.push(variable_index);
+            Self::assign(&mut expressions, variable_index, expression);
+        }
+
+        let values: Vec<Ratio<i32>> = expressions.iter()
+            .map(|x| x.as_ref().map(|y| y[variables_count]))
+            .collect::<Option<_>>()
+            .ok_or(BalancerError::ReactionCanBeEqualizedInInfiniteNumberOfWays)?;
+
+        if !eliminated_indices.is_empty() {
+            eliminated_indices.sort_unstable();
+            steps.push(AlgebraicStep::BackSubstitution {
+                values: eliminated_indices.iter().map(|&i| values[i]).collect(),
+                variables_indices: eliminated_indices,
+            });
+        }
+
+        Ok(Some((steps, values)))
+    }
+
+    /// Returns the expression of the constant.
+    fn get_constant_expression(variables_count: usize, value: Ratio<i32>) -> Expression {
+        let mut expression = vec![Ratio::zero(); variables_count + 1];
+        expression[variables_count] = value;
+
+        expression
+    }
+
+    /// Sets the expression of the variable, replacing the variable in the other expressions.
+    fn assign(
+        expressions: &mut [Option<Expression>],
+        variable_index: usize,
+        expression: Expression,
+    ) {
+        for x in expressions.iter_mut().flatten() {
+            let count = x[variable_index];
+
+            if !count.is_zero() {
+                for (y, z) in x.iter_mut().zip(&expression) {
+                    *y += count * z;
+                }
+
+                x[variable_index] = Ratio::zero();
+            }
+        }
+
+        expressions[variable_index] = Some(expression);
+    }
+
+    /// Returns the sum of the counts of the variables in the range multiplied by the sign,
+    /// with the variables replaced by their expressions.
+    fn resolve(
+        counts: &[i32],
+        indices: Range<usize>,
+        sign: i32,
+        expressions: &[Option<Expression>],
+    ) -> Expression {
+        let mut result = vec![Ratio::zero(); counts.len() + 1];
+
+        for j in indices.filter(|&j| counts[j] != 0) {
+            let count = Ratio::from_integer(counts[j] * sign);
+
+            match &expressions[j] {
+                Some(x) => for (y, z) in result.iter_mut().zip(x) {
+                    *y += count * z;
+                },
+                None => result[j] += count,
+            }
+        }
+
+        result
+    }
+
+    /// Returns the equations of the elements, followed by the equation of the charge if any
+    /// species is charged.
+    fn get_equations(equation: &Equation) -> Vec<(String, Vec<i32>)> {
+        let compositions = equation.get_compositions();
+        let reactants_count = equation.reactants.len();
+        let get_sign = |j: usize| if j < reactants_count { 1 } else { -1 };
+
+        let mut equations: Vec<(String, Vec<i32>)> = Balancer::get_rows_names(&compositions)
+            .into_iter()
+            .map(|name| (
+                name.to_string(),
+                compositions.iter().enumerate()
+                    .map(|(j, x)| get_sign(j) * x.count_element_by_name(name))
+                    .collect(),
+            ))
+            .collect();
+
+        if compositions.iter().any(|x| x.charge != 0) {
+            equations.push((
+                "charge".to_string(),
+                compositions.iter().enumerate().map(|(j, x)| get_sign(j) * x.charge).collect(),
+            ));
+        }
+
+        equations
+    }
+
+    /// Returns the letter of the variable by its index: a to z, then a1 to z1 and so on.
+    pub fn get_variable_name(variable_index: usize) -> String {
+        let letter = char::from(b'a' + (variable_index % 26) as u8);
+
+        if variable_index < 26 {
+            letter.to_string()
+        } else {
+            [letter.to_string(), (variable_index / 26).to_string()].join("")
+        }
+    }
+
+    /// Returns the minus sign of the format.
+    fn get_minus_sign(is_latex: bool) -> &'static str {
+        if is_latex { "-" } else { "\u{2212}" }
+    }
+
+    /// Formats a rational number.
+    fn format_ratio(value: Ratio<i32>, is_latex: bool) -> String {
+        let sign = if value < Ratio::zero() { Self::get_minus_sign(is_latex) } else { "" };
+        let value = if value < Ratio::zero() { -value } else { value };
+
+        if value.is_integer() {
+            format!("{sign}{}", value.numer())
+        } else if is_latex {
+            format!("{sign}\\frac{{{}}}{{{}}}", value.numer(), value.denom())
+        } else {
+            format!("{sign}{}/{}", value.numer(), value.denom())
+        }
+    }
+
+    /// Formats a sum of the terms: the counts of the variables and a constant, or 0 if there
+    /// are none.
+    fn format_sum(terms: &[(Ratio<i32>, usize)], constant: Ratio<i32>, is_latex: bool) -> String {
+        let minus_sign = Self::get_minus_sign(is_latex);
+        let mut parts: Vec<(bool, String)> = terms.iter()
+            .map(|&(count, variable_index)| {
+                let variable_name = Self::get_variable_name(variable_index);
+                let count_text = Self::format_ratio(count.abs(), is_latex);
+
+                if count.abs().is_one() {
+                    (count.is_negative(), variable_name)
+                } else if count.is_integer() || is_latex {
+                    (count.is_negative(), format!("{count_text}{variable_name}"))
+                } else {
+                    (count.is_negative(), format!("({count_text}){variable_name}"))
+                }
+            })
+            .collect();
+
+        if !constant.is_zero() || parts.is_empty() {
+            parts.push((constant < Ratio::zero(), Self::format_ratio(constant.abs(), is_latex)));
+        }
+
+        parts.iter().enumerate()
+            .map(|(i, (is_negative, text))| match (i, is_negative) {
+                (0, true) => [minus_sign, text].join(""),
+                (0, false) => text.clone(),
+                (_, true) => [" ", minus_sign, " ", text].join(""),
+                (_, false) => [" + ", text].join(""),
+            })
+            .join("")
+    }
+
+    /// Formats an expression as a sum of the terms of the variables and the constant.
+    fn format_expression(expression: &[Ratio<i32>], is_latex: bool) -> String {
+        let variables_count = expression.len() - 1;
+        let terms: Vec<(Ratio<i32>, usize)> = (0..variables_count)
+            .filter(|&j| !expression[j].is_zero())
+            .map(|j| (expression[j], j))
+            .collect();
+
+        Self::format_sum(&terms, expression[variables_count], is_latex)
+    }
+
+    /// Formats an equation of the variables with the reactants on the left side, replacing
+    /// the variables by their expressions.
+    fn format_equation(
+        &self,
+        counts: &[i32],
+        expressions: &[Option<Expression>],
+        is_latex: bool,
+    ) -> String {
+        let reactants_count = self.equation.reactants.len();
+
+        [
+            Self::resolve(counts, 0..reactants_count, 1, expressions),
+            Self::resolve(counts, reactants_count..counts.len(), -1, expressions),
+        ].iter()
+            .map(|x| Self::format_expression(x, is_latex))
+            .join(" = ")
+    }
+
+    /// Formats the name of the equation.
+    fn format_equation_name(name: &str, is_latex: bool) -> String {
+        match (name, is_latex) {
+            ("charge", true) => "\\text{charge}".to_string(),
+            (_, true) => format!("\\mathrm{{{name}}}"),
+            (_, false) => name.to_string(),
+        }
+    }
+
+    /// Formats the species with the coefficients, which are the variables or the numbers.
+    fn format_species(&self, coefficients: &[String], is_latex: bool) -> String {
+        let format_side = |entities: &[Entity], offset: usize| entities.iter().enumerate()
+            .filter(|(i, _)| coefficients[offset + i] != "0")
+            .map(|(i, x)| {
+                let coefficient = &coefficients[offset + i];
+                let separator = if is_latex { "\\," } else { "\u{a0}" };
+                let formula = if is_latex { x.format_latex() } else { x.format() };
+
+                if coefficient == "1" {
+                    formula
+                } else {
+                    [coefficient, separator, &formula].join("")
+                }
+            })
+            .join(" + ");
+
+        [
+            format_side(&self.equation.reactants, 0),
+            format_side(&self.equation.products, self.equation.reactants.len()),
+        ].join(" = ")
+    }
+
+    /// Formats the steps of the solution.
+    fn format_steps(&self, is_latex: bool) -> Vec<String> {
+        let math = |x: String| if is_latex { format!("${x}$") } else { x };
+        let variables_count = self.coefficients.len();
+        let mut expressions: Vec<Option<Expression>> = vec![None; variables_count];
+        let mut lines = vec![];
+
+        for step in &self.steps {
+            lines.push(match step {
+                AlgebraicStep::Assumption { variable_index } => {
+                    Self::assign(
+                        &mut expressions,
+                        *variable_index,
+                        Self::get_constant_expression(variables_count, Ratio::one()),
+                    );
+
+                    format!(
+                        "Let {}.",
+                        math(format!("{} = 1", Self::get_variable_name(*variable_index))),
+                    )
+                },
+                AlgebraicStep::Substitution { equation_index, variable_index, value } => {
+                    let (name, counts) = &self.equations[*equation_index];
+                    let line = format!(
+                        "From {}: {}, so {}.",
+                        math(Self::format_equation_name(name, is_latex)),
+                        math(self.format_equation(counts, &expressions, is_latex)),
+                        math(format!(
+                            "{} = {}",
+                            Self::get_variable_name(*variable_index),
+                            Self::format_ratio(*value, is_latex),
+                        )),
+                    );
+
+                    Self::assign(
+                        &mut expressions,
+                        *variable_index,
+                        Self::get_constant_expression(variables_count, *value),
+                    );
+
+                    line
+                },
+                AlgebraicStep::Elimination { equation_index, variable_index, terms, constant } => {
+                    let (name, counts) = &self.equations[*equation_index];
+                    let line = format!(
+                        "From {}: {}, so {}.",
+                        math(Self::format_equation_name(name, is_latex)),
+                        math(self.format_equation(counts, &expressions, is_latex)),
+                        math(format!(
+                            "{} = {}",
+                            Self::get_variable_name(*variable_index),
+                            Self::format_sum(terms, *constant, is_latex),
+                        )),
+                    );
+                    let mut expression = Self::get_constant_expression(variables_count, *constant);
+
+                    for &(count, j) in terms {
+                        expression[j] = count;
+                    }
+
+                    Self::assign(&mut expressions, *variable_index, expression);
+
+                    line
+                },
+                AlgebraicStep::BackSubstitution { variables_indices, values } => format!(
+                    "Substituting back gives {}.",
+                    math(variables_indices.iter().zip(values)
+                        .map(|(&i, &x)| format!(
+                            "{} = {}",
+                            Self::get_variable_name(i),
+                            Self::format_ratio(x, is_latex),
+                        ))
+                        .join(", ")),
+                ),
+                AlgebraicStep::Scaling { factor, coefficients } => {
+                    let values = math(coefficients.iter().enumerate()
+                        .map(|(i, x)| format!("{} = {x}", Self::get_variable_name(i)))
+                        .join(", "));
+
+                    if *factor == 1 {
+                        format!("All the values are integers: {values}.")
+                    } else {
+                        format!(
+                            "Multiply by {} to get integers: {values}.",
+                            Self::format_ratio(Ratio::from_integer(*factor), is_latex),
+                        )
+                    }
+                },
+            });
+        }
+
+        lines
+    }
+
+    /// Formats the derivation as plain text.
+    pub fn format_text(&self) -> String {
+        let variables: Vec<String> = (0..self.coefficients.len())
+            .map(Self::get_variable_name)
+            .collect();
+        let coefficients: Vec<String> = self.coefficients.iter().map(|x| x.to_string()).collect();
+        let no_expressions = vec![None; self.coefficients.len()];
+
+        [
+            format!("Let {}.", self.format_species(&variables, false)),
+            self.equations.iter()
+                .map(|(name, counts)| format!(
+                    "{name}: {}",
+                    self.format_equation(counts, &no_expressions, false),
+                ))
+                .join("\n"),
+            self.format_steps(false).join("\n"),
+            self.format_species(&coefficients, false),
+        ].join("\n\n")
+    }
+
+    /// Formats the derivation as a LaTeX fragment.
+    pub fn format_latex(&self) -> String {
+        let variables: Vec<String> = (0..self.coefficients.len())
+            .map(Self::get_variable_name)
+            .collect();
+        let coefficients: Vec<String> = self.coefficients.iter().map(|x| x.to_string()).collect();
+        let no_expressions = vec![None; self.coefficients.len()];
+
+        [
+            format!("Let ${}$.", self.format_species(&variables, true)),
+            [
+                "\\begin{align*}".to_string(),
+                self.equations.iter()
+                    .map(|(name, counts)| format!(
+                        "{} &: {}",
+                        Self::format_equation_name(name, true),
+                        self.format_equation(counts, &no_expressions, true),
+                    ))
+                    .join(" \\\\\n"),
+                "\\end{align*}".to_string(),
+            ].join("\n"),
+            self.format_steps(true).join("\n\n"),
+            format!("${}$", self.format_species(&coefficients, true)),
+        ].join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::{
+        algebraic_solution::AlgebraicSolution,
+        algebraic_step::AlgebraicStep,
+        balancer::Balancer,
+        parser::Parser,
+    };
+
+    fn get_solution(equation: &str) -> AlgebraicSolution {
+        AlgebraicSolution::new(&Parser::new(equation).parse_equation().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let solution = get_solution("H2 + O2 = H2O");
+        assert_eq!(
+            solution.equations,
+            [("H".to_string(), vec![2, 0, -2]), ("O".to_string(), vec![0, 2, -1])]
+        );
+        assert_eq!(solution.steps, [
+            AlgebraicStep::Assumption { variable_index: 0 },
+            AlgebraicStep::Substitution {
+                equation_index: 0,
+                variable_index: 2,
+                value: Ratio::from_integer(1),
+            },
+            AlgebraicStep::Substitution {
+                equation_index: 1,
+                variable_index: 1,
+                value: Ratio::new(1, 2),
+            },
+            AlgebraicStep::Scaling { factor: 2, coefficients: vec![2, 1, 2] },
+        ]);
+
+        let equations = [
+            "KMnO4 + HCl = KCl + MnCl2 + H2O + Cl2",
+            "Cr2O7{2-} + H{+} + e = Cr{3+} + H2O",
+            "Cu + HNO3 = Cu(NO3)2 + NO + H2O",
+            "KMnO4 + FeSO4 + H2SO4 = MnSO4 + Fe2(SO4)3 + K2SO4 + H2O",
+            "N2 + H2 + O2 = NH4NO3",
+        ];
+
+        for equation in equations {
+            assert_eq!(
+                get_solution(equation).coefficients,
+                Balancer::new(equation).unwrap().balance().unwrap().coefficients
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_with_elimination() {
+        let solution = get_solution("Cu + HNO3 = Cu(NO3)2 + NO + H2O");
+        assert_eq!(solution.steps, [
+            AlgebraicStep::Assumption { variable_index: 0 },
+            AlgebraicStep::Substitution {
+                equation_index: 0,
+                variable_index: 2,
+                value: Ratio::from_integer(1),
+            },
+            AlgebraicStep::Elimination {
+                equation_index: 1,
+                variable_index: 1,
+                terms: vec![(Ratio::from_integer(2), 4)],
+                constant: Ratio::from_integer(0),
+            },
+            AlgebraicStep::Elimination {
+                equation_index: 2,
+                variable_index: 3,
+                terms: vec![(Ratio::from_integer(2), 4)],
+                constant: Ratio::from_integer(-2),
+            },
+            AlgebraicStep::Substitution {
+                equation_index: 3,
+                variable_index: 4,
+                value: Ratio::new(4, 3),
+            },
+            AlgebraicStep::BackSubstitution {
+                variables_indices: vec![1, 3],
+                values: vec![Ratio::new(8, 3), Ratio::new(2, 3)],
+            },
+            AlgebraicStep::Scaling { factor: 3, coefficients: vec![3, 8, 3, 2, 4] },
+        ]);
+
+        let text = solution.format_text();
+        assert!(text.contains("From N: 2e = d + 2, so d = 2e \u{2212} 2."));
+        assert!(text.contains("Substituting back gives b = 8/3, d = 2/3."));
+    }
+
+    #[test]
+    fn test_get_variable_name() {
+        assert_eq!(AlgebraicSolution::get_variable_name(0), "a");
+        assert_eq!(AlgebraicSolution::get_variable_name(25), "z");
+        assert_eq!(AlgebraicSolution::get_variable_name(27), "b1");
+    }
+
+    #[test]
+    fn test_format_text() {
+        assert_eq!(
+            get_solution("H2 + O2 = H2O").format_text(),
+            "Let a\u{a0}H2 + b\u{a0}O2 = c\u{a0}H2O.\n\n\
+                H: 2a = 2c\n\
+                O: 2b = c\n\n\
+                Let a = 1.\n\
+                From H: 2 = 2c, so c = 1.\n\
+                From O: 2b = 1, so b = 1/2.\n\
+                Multiply by 2 to get integers: a = 2, b = 1, c = 2.\n\n\
+                2\u{a0}H2 + O2 = 2\u{a0}H2O"
+        );
+        assert!(get_solution("Fe{3+} + e = Fe{2+}").format_text()
+            .contains("charge: 3a \u{2212} b = 2c"));
+    }
+
+    #[test]
+    fn test_format_latex() {
+        assert_eq!(
+            get_solution("H2 + O2 = H2O").format_latex(),
+            "Let $a\\,\\mathrm{H_{2}} + b\\,\\mathrm{O_{2}} = c\\,\\mathrm{H_{2}O}$.\n\n\
+                \\begin{align*}\n\
+                \\mathrm{H} &: 2a = 2c \\\\\n\
+                \\mathrm{O} &: 2b = c\n\
+                \\end{align*}\n\n\
+                Let $a = 1$.\n\n\
+                From $\\mathrm{H}$: $2 = 2c$, so $c = 1$.\n\n\
+                From $\\mathrm{O}$: $2b = 1$, so $b = \\frac{1}{2}$.\n\n\
+                Multiply by 2 to get integers: $a = 2, b = 1, c = 2$.\n\n\
+                $2\\,\\mathrm{H_{2}} + \\mathrm{O_{2}} = 2\\,\\mathrm{H_{2}O}$"
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use num::rational::Ratio;
+
+/// A step of the algebraic method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlgebraicStep {
+    /// The variable is set to 1.
+    Assumption { variable_index: usize },
+    /// The equation with one unknown variable left is solved for it.
+    Substitution { equation_index: usize, variable_index: usize, value: Ratio<i32> },
+    /// The equation with several unknown variables left is solved for one of them, as the
+    /// sum of the terms of the other unknown variables and the constant, which replaces it
+    /// in the equations left.
+    Elimination {
+        equation_index: usize,
+        variable_index: usize,
+        terms: Vec<(Ratio<i32>, usize)>,
+        constant: Ratio<i32>,
+    },
+    /// The values of the eliminated variables are found from their expressions.
+    BackSubstitution { variables_indices: Vec<usize>, values: Vec<Ratio<i32>> },
+    /// The values are multiplied by the factor to get the integer coefficients.
+    Scaling { factor: i32, coefficients: Vec<i32> },
+}
@@ -0,0 +1,102 @@
+use crate::{
+    added_species::AddedSpecies,
+    balancer_warning::BalancerWarning,
+    charge_style::ChargeStyle,
+    equation::Equation,
+    explanation::Explanation,
+    inspection_step::InspectionStep,
+    notation::Notation,
+    redox_analysis::RedoxAnalysis,
+    removed_species::RemovedSpecies,
+};
+
+/// The result of balancing: the equation that is balanced, its coefficients and the diagnostics.
+#[derive(Debug, Clone)]
+pub struct BalancedEquation {
+    /// Equation that is balanced, without the removed species
+    pub equation: Equation,
+    /// Coefficients of the reactants followed by the products
+    pub coefficients: Vec<i32>,
+    /// Warnings
+    pub warnings: Vec<BalancerWarning>,
+    /// Species removed from the equation before balancing
+    pub removed_species: Vec<RemovedSpecies>,
+    /// Species of the medium added to complete the equation before balancing
+    pub added_species: Vec<AddedSpecies>,
+    /// Steps of the matrix method, if the explanation is enabled
+    pub explanation: Option<Explanation>,
+    /// Steps of balancing by inspection, empty with the matrix strategy
+    pub inspection_steps: Vec<InspectionStep>,
+}
+
+impl BalancedEquation {
+    /// BalancedEquation constructor.
+    pub fn new(
+        equation: Equation,
+        coefficients: Vec<i32>,
+        warnings: Vec<BalancerWarning>,
+        removed_species: Vec<RemovedSpecies>,
+    ) -> Self {
+        Self {
+            equation,
+            coefficients,
+            warnings,
+            removed_species,
+            added_species: vec![],
+            explanation: None,
+            inspection_steps: vec![],
+        }
+    }
+
+    /// Returns the changes of the oxidation states with the oxidizing and reducing agents.
+    pub fn get_redox_analysis(&self) -> RedoxAnalysis {
+        RedoxAnalysis::new(&self.equation, &self.coefficients)
+    }
+
+    /// Formats the balanced equation.
+    pub fn format(&self) -> String {
+        self.format_with_notation(Notation::AsWritten)
+    }
+
+    /// Formats the balanced equation in the notation.
+    pub fn format_with_notation(&self, notation: Notation) -> String {
+        self.equation.format_with_notation(&self.coefficients, notation)
+    }
+
+    /// Formats the balanced equation in the notation with the charges in the style.
+    pub fn format_with_charge_style(
+        &self,
+        notation: Notation,
+        charge_style: ChargeStyle,
+    ) -> String {
+        self.equation.format_with_charge_style(&self.coefficients, notation, charge_style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{balanced_equation::BalancedEquation, notation::Notation, parser::Parser};
+
+    #[test]
+    fn test_get_redox_analysis() {
+        let equation = Parser::new("H2 + O2 = H2O").parse_equation().unwrap();
+        let balanced_equation = BalancedEquation::new(equation, vec![2, 1, 2], vec![], vec![]);
+        let redox_analysis = balanced_equation.get_redox_analysis();
+        assert_eq!(redox_analysis.oxidizing_agents[0].format(), "O2");
+        assert_eq!(redox_analysis.reducing_agents[0].format(), "H2");
+    }
+
+    #[test]
+    fn test_format_with_notation() {
+        let equation = Parser::new("CH3COOH + O2 = CO2 + H2O").parse_equation().unwrap();
+        let balanced_equation = BalancedEquation::new(equation, vec![1, 2, 2, 2], vec![], vec![]);
+        assert_eq!(
+            balanced_equation.format(),
+            "CH3COOH + 2\u{a0}O2 = 2\u{a0}CO2 + 2\u{a0}H2O"
+        );
+        assert_eq!(
+            balanced_equation.format_with_notation(Notation::Hill),
+            "C2H4O2 + 2\u{a0}O2 = 2\u{a0}CO2 + 2\u{a0}H2O"
+        );
+    }
+}
@@ -1,45 +1,205 @@
 use num::integer::lcm;
+use std::collections::HashMap;
 use crate::{
+    added_species::AddedSpecies,
+    balanced_equation::BalancedEquation,
     balancer_error::BalancerError,
+    balancer_warning::BalancerWarning,
+    balancing_step::BalancingStep,
+    balancing_strategy::BalancingStrategy,
+    charge_style::ChargeStyle,
+    composition::Composition,
+    entity::Entity,
     equation::Equation,
-    item::Item,
+    explanation::Explanation,
+    inspection_step::InspectionStep,
+    inspector::Inspector,
     matrix::Matrix,
+    medium::Medium,
+    notation::Notation,
     parser::Parser,
     parser_error::ParserError,
+    removal_reason::RemovalReason,
+    removed_species::RemovedSpecies,
 };
 
 pub struct Balancer {
     /// Equation
     equation: Equation,
+    /// Compositions of the reactants followed by the products
+    compositions: Vec<Composition>,
     /// Matrix
     matrix: Matrix,
+    /// Notation of the formulas in the balanced equation
+    notation: Notation,
+    /// Style of the charges in the balanced equation
+    charge_style: ChargeStyle,
+    /// Whether the species on both sides are cancelled before balancing
+    is_cancellation_enabled: bool,
+    /// Whether the species with negative coefficients are moved to the other side
+    is_moving_enabled: bool,
+    /// Strategy of finding the coefficients
+    strategy: BalancingStrategy,
+    /// Whether the steps of the matrix method are recorded
+    is_explanation_enabled: bool,
+    /// Recorded steps of the matrix method
+    steps: Vec<BalancingStep>,
+    /// Medium whose species complete the equation if it does not balance as written
+    completion_medium: Option<Medium>,
 }
 
 impl Balancer {
     /// Balancer constructor.
     pub fn new(equation: &str) -> Result<Self, ParserError> {
-        let equation = Parser::new(equation).parse_equation()?;
-        let matrix = Self::get_initial_matrix(&equation);
+        Ok(Self::from_equation(Parser::new(equation).parse_equation()?))
+    }
 
-        Ok(Self { equation, matrix })
+    /// Creates a balancer from the parsed equation.
+    pub fn from_equation(equation: Equation) -> Self {
+        let compositions = equation.get_compositions();
+        let matrix = Self::get_initial_matrix(&compositions, equation.reactants.len());
+
+        Self {
+            equation,
+            compositions,
+            matrix,
+            notation: Notation::AsWritten,
+            charge_style: ChargeStyle::Braces,
+            is_cancellation_enabled: false,
+            is_moving_enabled: false,
+            strategy: BalancingStrategy::Matrix,
+            is_explanation_enabled: false,
+            steps: vec![],
+            completion_medium: None,
+        }
     }
 
-    /// Returns an initial matrix.
-    fn get_initial_matrix(equation: &Equation) -> Matrix {
-        let elements_names = equation.get_elements_names();
-        let mut matrix = Matrix::new(
-            elements_names.len() + 1,
-            equation.reactants.len() + equation.products.len() + 1
-        );
+    /// Sets whether the species with negative coefficients are moved to the other side
+    /// instead of failing.
+    pub fn with_moving(mut self, is_moving_enabled: bool) -> Self {
+        self.is_moving_enabled = is_moving_enabled;
+        self
+    }
+
+    /// Sets whether the species on both sides are cancelled before balancing, leaving
+    /// the net equation.
+    pub fn with_cancellation(mut self, is_cancellation_enabled: bool) -> Self {
+        self.is_cancellation_enabled = is_cancellation_enabled;
+        self
+    }
+
+    /// Sets the strategy of finding the coefficients.
+    pub fn with_strategy(mut self, strategy: BalancingStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets whether the steps of the matrix method are recorded in the balanced equation.
+    pub fn with_explanation(mut self, is_explanation_enabled: bool) -> Self {
+        self.is_explanation_enabled = is_explanation_enabled;
+        self
+    }
 
-        for (i, element_name) in elements_names.iter().enumerate() {
-            for (j, reactant) in equation.reactants.iter().enumerate() {
-                matrix.cells[i][j] = reactant.count_element_by_name(element_name) as i32;
+    /// Sets the medium whose water and ions are added to the equation if it does not balance
+    /// as written.
+    pub fn with_completion(mut self, completion_medium: Option<Medium>) -> Self {
+        self.completion_medium = completion_medium;
+        self
+    }
+
+    /// Sets the notation of the formulas in the balanced equation.
+    pub fn with_notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Sets the style of the charges in the balanced equation.
+    pub fn with_charge_style(mut self, charge_style: ChargeStyle) -> Self {
+        self.charge_style = charge_style;
+        self
+    }
+
+    /// Replaces the equation, recomputing the compositions and the initial matrix.
+    fn set_equation(&mut self, equation: Equation) {
+        self.compositions = equation.get_compositions();
+        self.matrix = Self::get_initial_matrix(&self.compositions, equation.reactants.len());
+        self.equation = equation;
+    }
+
+    /// Removes the pairs of identical reactants and products.
+    fn cancel_species(
+        &mut self,
+        pairs: &[(usize, usize)],
+    ) -> Result<Vec<RemovedSpecies>, BalancerError> {
+        let mut equation = self.equation.clone();
+        let mut removed_species = vec![];
+
+        for &(i, j) in pairs.iter().rev() {
+            let reactant = equation.reactants.remove(i);
+            let reason = RemovalReason::from_entity(&reactant);
+
+            removed_species.push(RemovedSpecies::new(
+                reactant,
+                self.equation.products[j].clone(),
+                reason,
+            ));
+        }
+
+        let mut products_indices: Vec<usize> = pairs.iter().map(|&(_, j)| j).collect();
+        products_indices.sort_unstable();
+
+        for &j in products_indices.iter().rev() {
+            equation.products.remove(j);
+        }
+
+        if equation.reactants.is_empty() || equation.products.is_empty() {
+            return Err(BalancerError::SpeciesCancelOut);
+        }
+
+        removed_species.reverse();
+        self.set_equation(equation);
+
+        Ok(removed_species)
+    }
+
+    /// Returns the names of the elements in the order of their rows in the matrix.
+    pub fn get_rows_names(compositions: &[Composition]) -> Vec<&str> {
+        let mut rows_names = vec![];
+
+        for x in compositions {
+            for element_name in x.get_elements_names() {
+                if !rows_names.contains(&element_name) {
+                    rows_names.push(element_name);
+                }
             }
+        }
 
-            for (j, product) in equation.products.iter().enumerate() {
-                matrix.cells[i][j + equation.reactants.len()]
-                    = -(product.count_element_by_name(element_name) as i32);
+        rows_names
+    }
+
+    /// Returns an initial matrix with a row for every element, a row for the charge if any
+    /// species is charged and an empty row.
+    fn get_initial_matrix(compositions: &[Composition], reactants_count: usize) -> Matrix {
+        let rows_indices: HashMap<&str, usize> = Self::get_rows_names(compositions)
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| (x, i))
+            .collect();
+
+        let charge_row_index = rows_indices.len();
+        let is_charged = compositions.iter().any(|x| x.charge != 0);
+        let rows_count = charge_row_index + usize::from(is_charged) + 1;
+        let mut matrix = Matrix::new(rows_count, compositions.len() + 1);
+
+        for (j, composition) in compositions.iter().enumerate() {
+            let sign = if j < reactants_count { 1 } else { -1 };
+
+            for (element_name, count) in &composition.elements {
+                matrix.cells[rows_indices[element_name.as_str()]][j] = sign * count;
+            }
+
+            if is_charged {
+                matrix.cells[charge_row_index][j] = sign * composition.charge;
             }
         }
 
@@ -48,7 +208,11 @@ impl Balancer {
 
     /// Solves a matrix.
     fn solve_matrix(&mut self) -> Result<(), BalancerError> {
-        self.matrix.eliminate();
+        if self.is_explanation_enabled {
+            self.steps.push(BalancingStep::InitialMatrix { matrix: self.matrix.clone() });
+        }
+
+        self.eliminate();
 
         let mut row_index = 0;
 
@@ -60,25 +224,46 @@ impl Balancer {
             row_index += 1;
         }
 
-        if row_index == self.matrix.rows_count - 1 {
+        if row_index >= (self.matrix.rows_count - 1).min(self.matrix.columns_count - 1) {
             return Err(BalancerError::AllCoefficientsAreZero);
         }
 
         self.matrix.cells[self.matrix.rows_count - 1][row_index] = 1;
         self.matrix.cells[self.matrix.rows_count - 1][self.matrix.columns_count - 1] = 1;
 
-        self.matrix.eliminate();
+        if self.is_explanation_enabled {
+            self.steps.push(BalancingStep::FreeVariable {
+                column_index: row_index,
+                matrix: self.matrix.clone(),
+            });
+        }
+
+        self.eliminate();
 
         Ok(())
     }
 
+    /// Eliminates the matrix, recording the operations if the explanation is enabled.
+    fn eliminate(&mut self) {
+        if !self.is_explanation_enabled {
+            self.matrix.eliminate();
+            return;
+        }
+
+        let steps = &mut self.steps;
+
+        self.matrix.eliminate_with_callback(|operation, matrix| {
+            steps.push(BalancingStep::Elimination { operation, matrix: matrix.clone() });
+        });
+    }
+
     /// Counts the non-zero coefficients in row by its index.
     fn count_nonzero_coefficients_in_row(&self, row_index: usize) -> usize {
         self.matrix.cells[row_index].iter().filter(|&x| *x != 0).count()
     }
 
     /// Extracts the coefficients.
-    fn extract_coefficients(&self) -> Result<Vec<i32>, BalancerError> {
+    fn extract_coefficients(&mut self) -> Result<Vec<i32>, BalancerError> {
         let rows_count = self.matrix.rows_count;
         let columns_count = self.matrix.columns_count;
 
@@ -102,15 +287,19 @@ impl Balancer {
             coefficients.push(coefficient);
         }
 
+        if self.is_explanation_enabled {
+            self.steps.push(BalancingStep::Scaling {
+                least_common_multiple,
+                coefficients: coefficients.clone(),
+            });
+        }
+
         Ok(coefficients)
     }
 
-    /// Checks the answer.
-    fn check_answer(&self, coefficients: &[i32]) -> Result<(), BalancerError> {
-        let reactants = &self.equation.reactants;
-        let products = &self.equation.products;
-
-        if reactants.len() + products.len() != coefficients.len() {
+    /// Checks that the coefficients balance the atoms and the charge of the equation.
+    pub fn check_answer(&self, coefficients: &[i32]) -> Result<(), BalancerError> {
+        if self.compositions.len() != coefficients.len() {
             return Err(BalancerError::MismatchInNumberOfCoefficients);
         }
 
@@ -118,41 +307,365 @@ impl Balancer {
             return Err(BalancerError::AllCoefficientsAreZero);
         }
 
-        let mut sum = 0;
-        let reactants_count = reactants.len();
+        let reactants_count = self.equation.reactants.len();
+        let mut difference = Composition::default();
+
+        for (i, x) in self.compositions.iter().enumerate() {
+            let sign = if i < reactants_count { 1 } else { -1 };
+            difference = difference.add(&x.scale(sign * coefficients[i]));
+        }
+
+        if difference.is_empty() {
+            Ok(())
+        } else if difference.elements.is_empty() {
+            Err(BalancerError::ChargeIsNotBalanced { difference: -difference.charge })
+        } else {
+            Err(BalancerError::AtomsAreNotBalanced {
+                names: difference.get_elements_names().into_iter().map(String::from).collect(),
+            })
+        }
+    }
+
+    /// Returns the reason why the coefficients are all zero: the elements present on one side
+    /// only, or else the charge left over by the coefficients that balance the atoms.
+    fn diagnose_imbalance(&self) -> Option<BalancerError> {
+        let reactants_count = self.equation.reactants.len();
+        let (reactants_compositions, products_compositions) =
+            self.compositions.split_at(reactants_count);
+        let is_present = |compositions: &[Composition], element_name: &str| compositions.iter()
+            .any(|x| x.count_element_by_name(element_name) != 0);
+        let names: Vec<String> = Self::get_rows_names(&self.compositions).into_iter()
+            .filter(|x| {
+                is_present(reactants_compositions, x) != is_present(products_compositions, x)
+            })
+            .map(String::from)
+            .collect();
+
+        if !names.is_empty() {
+            return Some(BalancerError::AtomsAreNotBalanced { names });
+        }
+
+        if self.compositions.iter().all(|x| x.charge == 0) {
+            return None;
+        }
+
+        let mut balancer = Self::from_equation(self.equation.clone());
+        balancer.compositions = self.compositions.iter()
+            .map(|x| Composition::new(x.elements.clone(), 0))
+            .collect();
+        balancer.matrix = Self::get_initial_matrix(&balancer.compositions, reactants_count);
+
+        let mut coefficients = balancer.solve_matrix()
+            .and_then(|_| balancer.extract_coefficients())
+            .ok()?;
+        balancer.check_answer(&coefficients).ok()?;
+        Self::normalize_signs(&mut coefficients);
+
+        let difference = self.compositions.iter().zip(&coefficients).enumerate()
+            .map(|(i, (x, y))| if i < reactants_count { -x.charge * y } else { x.charge * y })
+            .sum();
+
+        Some(BalancerError::ChargeIsNotBalanced { difference })
+    }
+
+    /// Changes the signs of the coefficients if most of them are negative.
+    fn normalize_signs(coefficients: &mut [i32]) {
+        let negative_count = coefficients.iter().filter(|&&x| x < 0).count();
+        let positive_count = coefficients.iter().filter(|&&x| x > 0).count();
+
+        if negative_count > positive_count
+            || negative_count == positive_count && coefficients.first().is_some_and(|&x| x < 0) {
+            for x in coefficients.iter_mut() {
+                *x = -*x;
+            }
+        }
+    }
+
+    /// Moves the species with negative coefficients to the end of the other side.
+    fn move_negative_species(&mut self, coefficients: &mut Vec<i32>) -> Vec<BalancerWarning> {
+        let reactants_count = self.equation.reactants.len();
+        let entities: Vec<_> = self.equation.reactants.iter()
+            .chain(&self.equation.products)
+            .zip(coefficients.iter().copied())
+            .enumerate()
+            .collect();
+        let mut reactants = vec![];
+        let mut products = vec![];
+        let mut reactants_coefficients = vec![];
+        let mut products_coefficients = vec![];
+        let mut warnings = vec![];
+
+        for is_moving in [false, true] {
+            for &(i, (entity, coefficient)) in &entities {
+                if (coefficient < 0) != is_moving {
+                    continue;
+                }
+
+                let is_reactant = (i < reactants_count) != is_moving;
+
+                if is_moving {
+                    warnings.push(BalancerWarning::SpeciesMovedToOtherSide {
+                        entity: entity.clone(),
+                        is_reactant,
+                    });
+                }
+
+                if is_reactant {
+                    reactants.push(entity.clone());
+                    reactants_coefficients.push(coefficient.abs());
+                } else {
+                    products.push(entity.clone());
+                    products_coefficients.push(coefficient.abs());
+                }
+            }
+        }
+
+        reactants_coefficients.append(&mut products_coefficients);
+        *coefficients = reactants_coefficients;
+        self.set_equation(Equation::new(reactants, products));
+
+        warnings
+    }
+
+    /// Returns the warnings about the species with zero coefficients.
+    fn get_zero_coefficients_warnings(&self, coefficients: &[i32]) -> Vec<BalancerWarning> {
+        let entities: Vec<_> = self.equation.reactants.iter()
+            .chain(&self.equation.products)
+            .collect();
+
+        coefficients.iter().enumerate().filter(|(_, &x)| x == 0).map(|(i, _)| {
+            let missing_elements = self.compositions[i].get_elements_names().into_iter()
+                .filter(|&x| self.compositions.iter().enumerate()
+                    .all(|(j, y)| j == i || y.count_element_by_name(x) == 0))
+                .map(String::from)
+                .collect();
+
+            BalancerWarning::ZeroCoefficient { entity: entities[i].clone(), missing_elements }
+        }).collect()
+    }
+
+    /// Balances the equation by inspection, returning the coefficients with its steps, or falls
+    /// back to the matrix method if inspection gets stuck.
+    fn inspect(
+        &mut self,
+        remaining_names: &[String],
+    ) -> Result<(Vec<i32>, Vec<InspectionStep>), BalancerError> {
+        let mut inspector = Inspector::new(&self.equation);
+        let mut steps = match inspector.balance() {
+            Some(x) => return Ok((x, inspector.steps)),
+            None => inspector.steps,
+        };
+
+        if let Some(InspectionStep::ClearFractions { .. }) = steps.last() {
+            steps.pop();
+        }
+
+        steps.push(InspectionStep::FallBackToMatrix);
+
+        Ok((self.solve_coefficients(remaining_names)?, steps))
+    }
+
+    /// Finds the coefficients by the matrix method, reporting the species left on both sides
+    /// if they make the solution not unique.
+    fn solve_coefficients(
+        &mut self,
+        remaining_names: &[String],
+    ) -> Result<Vec<i32>, BalancerError> {
+        self.solve_matrix()
+            .and_then(|_| self.extract_coefficients())
+            .map_err(|error| match error {
+                BalancerError::AllCoefficientsAreZero
+                | BalancerError::ReactionCanBeEqualizedInInfiniteNumberOfWays
+                    if !remaining_names.is_empty() =>
+                    BalancerError::SpeciesAppearOnBothSides { names: remaining_names.to_vec() },
+                BalancerError::AllCoefficientsAreZero => self.diagnose_imbalance().unwrap_or(error),
+                _ => error,
+            })
+    }
+
+    /// Returns the recorded steps with the names of the rows and columns, if the explanation
+    /// is enabled and the matrix method is used.
+    fn get_explanation(&mut self) -> Option<Explanation> {
+        if !self.is_explanation_enabled || self.steps.is_empty() {
+            return None;
+        }
+
+        Some(Explanation::new(
+            Self::get_rows_names(&self.compositions).iter().map(|x| x.to_string()).collect(),
+            self.equation.reactants.iter().chain(&self.equation.products).cloned().collect(),
+            std::mem::take(&mut self.steps),
+        ))
+    }
 
-        for x in &self.equation.get_elements_names() {
-            for (i, y) in reactants.iter().enumerate() {
-                sum += y.count_element_by_name(x) as i32 * coefficients[i];
+    /// Balances the equation with the smallest set of the species of the medium added that
+    /// makes every coefficient non-zero, if any.
+    fn complete(&mut self, equation: &Equation, medium: Medium) -> Option<BalancedEquation> {
+        let entities: Vec<&Entity> = equation.reactants.iter().chain(&equation.products).collect();
+
+        for species in medium.get_completions() {
+            if species.iter().any(|x| entities.contains(&x)) {
+                continue;
             }
 
-            for (i, y) in products.iter().enumerate() {
-                sum -= y.count_element_by_name(x) as i32 * coefficients[i + reactants_count];
+            let mut reactants = equation.reactants.clone();
+            reactants.extend(species.iter().cloned());
+
+            let mut balancer = Self::from_equation(
+                Equation::new(reactants, equation.products.clone()),
+            )
+                .with_cancellation(self.is_cancellation_enabled)
+                .with_moving(true)
+                .with_strategy(self.strategy)
+                .with_explanation(self.is_explanation_enabled);
+
+            let Ok(mut balanced_equation) = balancer.balance() else {
+                continue;
+            };
+
+            let mut is_complete = true;
+
+            balanced_equation.warnings.retain(|x| match x {
+                BalancerWarning::SpeciesMovedToOtherSide { entity, .. } => {
+                    is_complete &= species.contains(entity) || self.is_moving_enabled;
+                    !species.contains(entity)
+                },
+                BalancerWarning::ZeroCoefficient { .. } => {
+                    is_complete = false;
+                    true
+                },
+                _ => true,
+            });
+
+            let sides = [
+                (&balanced_equation.equation.reactants, true),
+                (&balanced_equation.equation.products, false),
+            ];
+            let added_species: Vec<AddedSpecies> = sides.into_iter()
+                .flat_map(|(x, is_reactant)| x.iter()
+                    .filter(|y| species.contains(y))
+                    .map(move |y| AddedSpecies::new(y.clone(), is_reactant)))
+                .collect();
+
+            // In the neutral medium the ions are only formed from water.
+            if medium == Medium::Neutral
+                && added_species.iter().any(|x| x.is_reactant && x.entity.charge != 0) {
+                is_complete = false;
             }
 
-            if sum != 0 {
-                return Err(BalancerError::CoefficientsAreIncorrectlyPlaced);
+            if is_complete {
+                self.set_equation(balanced_equation.equation.clone());
+                balanced_equation.added_species = added_species;
+
+                return Some(balanced_equation);
             }
         }
 
-        Ok(())
+        None
     }
 
-    /// Balances an equation.
-    pub fn balance_equation(&mut self) -> Result<String, BalancerError> {
-        self.solve_matrix()?;
+    /// Balances an equation, returning the coefficients and the diagnostics.
+    ///
+    /// If the completion is enabled and the equation does not balance as written, or some
+    /// species has a zero coefficient, the species of the medium are added to it.
+    pub fn balance(&mut self) -> Result<BalancedEquation, BalancerError> {
+        let Some(medium) = self.completion_medium else {
+            return self.balance_as_written();
+        };
+
+        let equation = self.equation.clone();
+        let result = self.balance_as_written();
+        let is_balanced = result.as_ref().is_ok_and(|x| !x.warnings.iter()
+            .any(|y| matches!(y, BalancerWarning::ZeroCoefficient { .. })));
+
+        if is_balanced {
+            return result;
+        }
+
+        self.complete(&equation, medium).map_or(result, Ok)
+    }
 
-        let coefficients = self.extract_coefficients()?;
+    /// Balances the equation without adding any species.
+    fn balance_as_written(&mut self) -> Result<BalancedEquation, BalancerError> {
+        let pairs = self.equation.find_species_on_both_sides();
+        let mut warnings: Vec<BalancerWarning> = pairs.iter()
+            .map(|&(i, j)| BalancerWarning::SpeciesOnBothSides {
+                reactant: self.equation.reactants[i].clone(),
+                product: self.equation.products[j].clone(),
+            })
+            .collect();
+        let identical_pairs: Vec<(usize, usize)> = pairs.iter()
+            .copied()
+            .filter(|&(i, j)| self.equation.reactants[i] == self.equation.products[j])
+            .collect();
+        let remaining_names: Vec<String> = pairs.iter()
+            .filter(|x| !self.is_cancellation_enabled || !identical_pairs.contains(x))
+            .map(|&(i, _)| self.equation.reactants[i].format())
+            .collect();
+        let removed_species = if self.is_cancellation_enabled && !identical_pairs.is_empty() {
+            self.cancel_species(&identical_pairs)?
+        } else {
+            vec![]
+        };
+
+        let (mut coefficients, inspection_steps) = match self.strategy {
+            BalancingStrategy::Matrix => (self.solve_coefficients(&remaining_names)?, vec![]),
+            BalancingStrategy::Inspection => self.inspect(&remaining_names)?,
+        };
 
         self.check_answer(&coefficients)?;
 
-        Ok(self.equation.format(&coefficients))
+        let explanation = self.get_explanation();
+
+        Self::normalize_signs(&mut coefficients);
+
+        if coefficients.iter().any(|&x| x < 0) {
+            if !self.is_moving_enabled {
+                let entities = self.equation.reactants.iter().chain(&self.equation.products);
+
+                return Err(BalancerError::ReactionIsImpossibleInThisDirection {
+                    names: entities.zip(&coefficients)
+                        .filter(|(_, &x)| x < 0)
+                        .map(|(x, _)| x.format())
+                        .collect(),
+                });
+            }
+
+            let mut moving_warnings = self.move_negative_species(&mut coefficients);
+            warnings.append(&mut moving_warnings);
+        }
+
+        warnings.extend(self.get_zero_coefficients_warnings(&coefficients));
+
+        let mut balanced_equation = BalancedEquation::new(
+            self.equation.clone(),
+            coefficients,
+            warnings,
+            removed_species,
+        );
+        balanced_equation.explanation = explanation;
+        balanced_equation.inspection_steps = inspection_steps;
+
+        Ok(balanced_equation)
+    }
+
+    /// Balances an equation.
+    pub fn balance_equation(&mut self) -> Result<String, BalancerError> {
+        Ok(self.balance()?.format_with_charge_style(self.notation, self.charge_style))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::balancer::Balancer;
+    use crate::{
+        balancer::Balancer,
+        balancer_error::BalancerError,
+        balancer_warning::BalancerWarning,
+        balancing_step::BalancingStep,
+        balancing_strategy::BalancingStrategy,
+        inspection_step::InspectionStep,
+        medium::Medium,
+        removal_reason::RemovalReason,
+    };
 
     #[test]
     fn test_new() {
@@ -161,7 +674,14 @@ mod tests {
 
     #[test]
     fn test_get_initial_matrix() {
-        // TODO
+        let balancer = Balancer::new("H2 + O2 = H2O").unwrap();
+        assert_eq!(
+            balancer.matrix.cells,
+            [[2, 0, -2, 0], [0, 2, -1, 0], [0, 0, 0, 0]]
+        );
+
+        let balancer = Balancer::new("Fe{3+} + e = Fe{2+}").unwrap();
+        assert_eq!(balancer.matrix.cells, [[1, 0, -1, 0], [3, -1, -2, 0], [0, 0, 0, 0]]);
     }
 
     #[test]
@@ -181,7 +701,251 @@ mod tests {
 
     #[test]
     fn test_check_answer() {
-        // TODO
+        let balancer = Balancer::new("Cr2O7{2-} + H{+} + e = Cr{3+} + H2O").unwrap();
+        assert!(balancer.check_answer(&[1, 14, 6, 2, 7]).is_ok());
+        assert!(matches!(
+            balancer.check_answer(&[1, 14, 5, 2, 7]),
+            Err(BalancerError::ChargeIsNotBalanced { difference: -1 })
+        ));
+        assert!(matches!(
+            balancer.check_answer(&[1, 14, 6, 2, 6]),
+            Err(BalancerError::AtomsAreNotBalanced { names }) if names == ["O", "H"]
+        ));
+        assert!(balancer.check_answer(&[1, 14, 6, 2]).is_err());
+        assert!(balancer.check_answer(&[0, 0, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_diagnose_imbalance() {
+        assert!(matches!(
+            Balancer::new("Fe{2+} = Fe{3+}").unwrap().balance(),
+            Err(BalancerError::ChargeIsNotBalanced { difference: 1 })
+        ));
+        assert!(matches!(
+            Balancer::new("Ag{+} + Cl{-} = AgCl{-}").unwrap().balance(),
+            Err(BalancerError::ChargeIsNotBalanced { difference: -1 })
+        ));
+        assert!(matches!(
+            Balancer::new("MnO4{-} + Fe{2+} = Mn{2+} + Fe{3+}").unwrap().balance(),
+            Err(BalancerError::AtomsAreNotBalanced { names }) if names == ["O"]
+        ));
+        assert!(matches!(
+            Balancer::new("H2 + O2 = H2O2 + H2O").unwrap().balance(),
+            Err(BalancerError::ReactionCanBeEqualizedInInfiniteNumberOfWays)
+        ));
+    }
+
+    #[test]
+    fn test_balance() {
+        let balanced_equation = Balancer::new("H2O2 + MnO2 = H2O + O2 + MnO2").unwrap()
+            .with_cancellation(true)
+            .balance()
+            .unwrap();
+        assert_eq!(balanced_equation.format(), "2\u{a0}H2O2 = 2\u{a0}H2O + O2");
+        assert_eq!(balanced_equation.warnings.len(), 1);
+        assert_eq!(balanced_equation.removed_species.len(), 1);
+        assert_eq!(balanced_equation.removed_species[0].reactant.format(), "MnO2");
+        assert_eq!(balanced_equation.removed_species[0].reason, RemovalReason::Catalyst);
+
+        let error = Balancer::new("H2O2 + MnO2 = H2O + O2 + MnO2").unwrap().balance();
+        assert!(matches!(
+            error,
+            Err(BalancerError::SpeciesAppearOnBothSides { names }) if names == ["MnO2"]
+        ));
+
+        let balanced_equation = Balancer::new("CH3COOH + H2O + NaOH = CH3COONa + H2O + H2O")
+            .unwrap()
+            .with_cancellation(true)
+            .balance()
+            .unwrap();
+        assert_eq!(balanced_equation.format(), "CH3COOH + NaOH = CH3COONa + H2O");
+        assert_eq!(balanced_equation.warnings.len(), 1);
+
+        let balanced_equation = Balancer::new("Ag{+} + NO3{-} + Cl{-} = AgCl + NO3{-}").unwrap()
+            .with_cancellation(true)
+            .balance()
+            .unwrap();
+        assert_eq!(balanced_equation.format(), "Ag{+} + Cl{\u{2212}} = AgCl");
+        assert_eq!(balanced_equation.removed_species[0].reason, RemovalReason::SpectatorIon);
+
+        let error = Balancer::new("H2O = H2O").unwrap().with_cancellation(true).balance();
+        assert!(matches!(error, Err(BalancerError::SpeciesCancelOut)));
+
+        let balanced_equation = Balancer::new("CH3COOH = C2H4O2").unwrap()
+            .with_cancellation(true)
+            .balance()
+            .unwrap();
+        assert_eq!(balanced_equation.format(), "CH3COOH = C2H4O2");
+        assert_eq!(balanced_equation.warnings.len(), 1);
+        assert!(balanced_equation.removed_species.is_empty());
+
+        let balanced_equation = Balancer::new("H2O(l) = H2O(g)").unwrap()
+            .with_cancellation(true)
+            .balance()
+            .unwrap();
+        assert_eq!(balanced_equation.format(), "H2O(l) = H2O(g)");
+        assert!(balanced_equation.warnings.is_empty());
+
+        let balanced_equation = Balancer::new("H2 + O2 + N2 = H2O").unwrap().balance().unwrap();
+        assert_eq!(balanced_equation.format(), "2\u{a0}H2 + O2 = 2\u{a0}H2O");
+        assert_eq!(
+            balanced_equation.warnings,
+            [BalancerWarning::ZeroCoefficient {
+                entity: "N2".parse().unwrap(),
+                missing_elements: vec!["N".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_normalize_signs() {
+        let mut coefficients = [1, -2, -2];
+        Balancer::normalize_signs(&mut coefficients);
+        assert_eq!(coefficients, [-1, 2, 2]);
+
+        let mut coefficients = [-1, 1];
+        Balancer::normalize_signs(&mut coefficients);
+        assert_eq!(coefficients, [1, -1]);
+    }
+
+    #[test]
+    fn test_move_negative_species() {
+        let mut balancer = Balancer::new("H2O + O2 = H2").unwrap();
+        let mut coefficients = vec![2, -1, 2];
+        let warnings = balancer.move_negative_species(&mut coefficients);
+        assert_eq!(coefficients, [2, 2, 1]);
+        assert_eq!(balancer.equation.format(&coefficients), "2\u{a0}H2O = 2\u{a0}H2 + O2");
+        assert_eq!(
+            warnings,
+            [BalancerWarning::SpeciesMovedToOtherSide {
+                entity: "O2".parse().unwrap(),
+                is_reactant: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_balance_with_negative_coefficients() {
+        let error = Balancer::new("O2 + H2O = H2").unwrap().balance();
+        assert!(matches!(
+            error,
+            Err(BalancerError::ReactionIsImpossibleInThisDirection { names }) if names == ["O2"]
+        ));
+
+        let balanced_equation = Balancer::new("H2O + Cl2 + NaOH = NaCl + H2").unwrap()
+            .with_moving(true)
+            .balance()
+            .unwrap();
+        assert_eq!(
+            balanced_equation.format(),
+            "Cl2 + 2\u{a0}NaOH + H2 = 2\u{a0}NaCl + 2\u{a0}H2O"
+        );
+        assert_eq!(balanced_equation.warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_balance_with_explanation() {
+        let balanced_equation = Balancer::new("H2 + O2 = H2O").unwrap().balance().unwrap();
+        assert_eq!(balanced_equation.explanation, None);
+
+        let balanced_equation = Balancer::new("H2 + O2 = H2O").unwrap()
+            .with_explanation(true)
+            .balance()
+            .unwrap();
+        let explanation = balanced_equation.explanation.unwrap();
+        assert_eq!(explanation.rows_names, ["H", "O"]);
+        assert_eq!(explanation.species.len(), 3);
+        assert!(matches!(
+            explanation.steps.first(),
+            Some(BalancingStep::InitialMatrix { matrix }) if matrix.cells[0] == [2, 0, -2, 0]
+        ));
+        assert!(explanation.steps.iter().any(|x| matches!(
+            x,
+            BalancingStep::FreeVariable { column_index: 0, .. }
+        )));
+        assert_eq!(
+            explanation.steps.last(),
+            Some(&BalancingStep::Scaling { least_common_multiple: 2, coefficients: vec![2, 1, 2] })
+        );
+    }
+
+    #[test]
+    fn test_balance_with_inspection() {
+        let balanced_equation = Balancer::new("C3H8 + O2 = CO2 + H2O").unwrap()
+            .with_strategy(BalancingStrategy::Inspection)
+            .balance()
+            .unwrap();
+        assert_eq!(balanced_equation.coefficients, [1, 5, 3, 4]);
+        assert_eq!(balanced_equation.inspection_steps.len(), 5);
+
+        let balanced_equation = Balancer::new("Cu + HNO3 = Cu(NO3)2 + NO + H2O").unwrap()
+            .with_strategy(BalancingStrategy::Inspection)
+            .balance()
+            .unwrap();
+        assert_eq!(balanced_equation.coefficients, [3, 8, 3, 2, 4]);
+        assert_eq!(
+            balanced_equation.inspection_steps.last(),
+            Some(&InspectionStep::FallBackToMatrix)
+        );
+
+        let balanced_equation = Balancer::new("C3H8 + O2 = CO2 + H2O").unwrap().balance().unwrap();
+        assert!(balanced_equation.inspection_steps.is_empty());
+
+        let equations = [
+            "C3H8 + O2 = CO2 + H2O",
+            "H2 + O2 = H2O",
+            "Fe2O3 + CO = Fe + CO2",
+            "Al + O2 = Al2O3",
+            "Cr2O7{2-} + H{+} + e = Cr{3+} + H2O",
+            "KMnO4 + HCl = KCl + MnCl2 + H2O + Cl2",
+        ];
+
+        for equation in equations {
+            let balanced_equation = Balancer::new(equation).unwrap()
+                .with_strategy(BalancingStrategy::Inspection)
+                .balance()
+                .unwrap();
+            assert_eq!(
+                balanced_equation.coefficients,
+                Balancer::new(equation).unwrap().balance().unwrap().coefficients
+            );
+        }
+    }
+
+    #[test]
+    fn test_balance_with_completion() {
+        let balanced_equation = Balancer::new("MnO4{-} + Fe{2+} = Mn{2+} + Fe{3+}").unwrap()
+            .with_completion(Some(Medium::Acidic))
+            .balance()
+            .unwrap();
+        assert_eq!(
+            balanced_equation.format(),
+            "MnO4{\u{2212}} + 5\u{a0}Fe{2+} + 8\u{a0}H{+} = Mn{2+} + 5\u{a0}Fe{3+} + 4\u{a0}H2O"
+        );
+        assert!(balanced_equation.warnings.is_empty());
+        assert_eq!(balanced_equation.added_species.len(), 2);
+        assert!(balanced_equation.added_species[0].is_reactant);
+        assert!(!balanced_equation.added_species[1].is_reactant);
+
+        let balanced_equation = Balancer::new("MnO4{-} + I{-} = MnO2 + I2").unwrap()
+            .with_completion(Some(Medium::Neutral))
+            .balance()
+            .unwrap();
+        assert_eq!(
+            balanced_equation.format(),
+            "2\u{a0}MnO4{\u{2212}} + 6\u{a0}I{\u{2212}} + 4\u{a0}H2O = 2\u{a0}MnO2 + 3\u{a0}I2 \
+                + 8\u{a0}OH{\u{2212}}"
+        );
+
+        let balanced_equation = Balancer::new("H2 + O2 = H2O").unwrap()
+            .with_completion(Some(Medium::Basic))
+            .balance()
+            .unwrap();
+        assert!(balanced_equation.added_species.is_empty());
+
+        assert!(Balancer::new("MnO4{-} + Fe{2+} = Mn{2+} + Fe{3+}").unwrap()
+            .balance()
+            .is_err());
     }
 
     #[test]
@@ -212,12 +976,12 @@ mod tests {
 
         let ionic_equations = [
             ["Fe{3+} + e = Fe", "Fe{3+} + 3\u{a0}e− = Fe"],
-            ["Cl{5+} + e = Cl{-}", "Cl{5+} + 6\u{a0}e− = Cl"],
-            ["CO3{2-} + H{+} = H2O + CO2", "CO3{2−} + 2\u{a0}H = H2O + CO2"],
-            ["CaCO3 + H{+} = Ca{2+} + CO2 + H2O", "CaCO3 + 2\u{a0}H = Ca{2+} + CO2 + H2O"],
+            ["Cl{5+} + e = Cl{-}", "Cl{5+} + 6\u{a0}e− = Cl{−}"],
+            ["CO3{2-} + H{+} = H2O + CO2", "CO3{2−} + 2\u{a0}H{+} = H2O + CO2"],
+            ["CaCO3 + H{+} = Ca{2+} + CO2 + H2O", "CaCO3 + 2\u{a0}H{+} = Ca{2+} + CO2 + H2O"],
             [
                 "Cr2O7{2-} + H{+} + e = Cr{3+} + H2O",
-                "Cr2O7{2−} + 14\u{a0}H + 6\u{a0}e− = 2\u{a0}Cr{3+} + 7\u{a0}H2O",
+                "Cr2O7{2−} + 14\u{a0}H{+} + 6\u{a0}e− = 2\u{a0}Cr{3+} + 7\u{a0}H2O",
             ],
         ];
 
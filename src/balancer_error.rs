@@ -1,20 +1,128 @@
 #[derive(Debug)]
 pub enum BalancerError {
+    /// The species of the activities do not appear in the equation.
+    ActivitiesAreNotMatched { names: Vec<String> },
     AllCoefficientsAreZero,
-    CoefficientsAreIncorrectlyPlaced,
+    /// The atoms of the elements cannot be balanced.
+    AtomsAreNotBalanced { names: Vec<String> },
+    /// The coefficients that balance the atoms leave the right side with the charge
+    /// difference.
+    ChargeIsNotBalanced { difference: i32 },
+    ElectronsAreNotTransferred,
+    HalfReactionsAreNotFound,
+    /// The compounds are not made of recognized ions.
+    IonsAreNotRecognized { names: Vec<String> },
     MismatchInNumberOfCoefficients,
+    /// The reactants of a precipitation reaction are insoluble.
+    ReactantsAreInsoluble { names: Vec<String> },
     ReactionCanBeEqualizedInInfiniteNumberOfWays,
+    ReactionIsImpossibleInThisDirection { names: Vec<String> },
+    SpeciesAppearOnBothSides { names: Vec<String> },
+    SpeciesCancelOut,
+    StandardPotentialIsNotFound { couple: String },
 }
 
 impl BalancerError {
     /// Returns the description.
-    pub fn get_description(&self) -> &str {
-        match self {
+    pub fn get_description(&self) -> String {
+        let description = match self {
+            Self::ActivitiesAreNotMatched { names } => return if names.len() == 1 {
+                format!("The activity of {} matches no species of the equation.", names[0])
+            } else {
+                format!("The activities of {} match no species of the equation.", names.join(", "))
+            },
             Self::AllCoefficientsAreZero => "All coefficients are zero.",
-            Self::CoefficientsAreIncorrectlyPlaced => "The coefficients are incorrectly placed.",
+            Self::AtomsAreNotBalanced { names } =>
+                return format!("The atoms of {} cannot be balanced.", names.join(", ")),
+            Self::ChargeIsNotBalanced { difference } => return format!(
+                "The charge cannot be balanced: it is off by {}{} on the right.",
+                if *difference < 0 { "\u{2212}" } else { "+" },
+                difference.abs(),
+            ),
+            Self::ElectronsAreNotTransferred =>
+                "The half-reactions are not an oxidation and a reduction transferring electrons.",
+            Self::HalfReactionsAreNotFound =>
+                "The equation cannot be split into two half-reactions by the elements they share.",
+            Self::IonsAreNotRecognized { names } =>
+                return format!("The ions of {} are not recognized.", names.join(", ")),
             Self::MismatchInNumberOfCoefficients => "Mismatch in the number of coefficients.",
+            Self::ReactantsAreInsoluble { names } => return format!(
+                "{} {} insoluble and cannot react in solution.",
+                names.join(", "),
+                if names.len() == 1 { "is" } else { "are" },
+            ),
             Self::ReactionCanBeEqualizedInInfiniteNumberOfWays =>
                 "The reaction can be equalized in an infinite number of ways.",
-        }
+            Self::ReactionIsImpossibleInThisDirection { names } => return format!(
+                "The reaction as written is impossible in this direction: {} {} on the wrong side.",
+                names.join(", "),
+                if names.len() == 1 { "is" } else { "are" },
+            ),
+            Self::SpeciesAppearOnBothSides { names } => return format!(
+                "The same species appear on both sides: {}; cancel them to balance the net \
+                    equation.",
+                names.join(", "),
+            ),
+            Self::SpeciesCancelOut => "All the species of a side cancel out.",
+            Self::StandardPotentialIsNotFound { couple } =>
+                return format!("No standard reduction potential is tabulated for {couple}."),
+        };
+
+        description.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::balancer_error::BalancerError;
+
+    #[test]
+    fn test_get_description() {
+        assert_eq!(
+            BalancerError::ActivitiesAreNotMatched { names: vec!["Cu{2+}".to_string()] }
+                .get_description(),
+            "The activity of Cu{2+} matches no species of the equation."
+        );
+        assert_eq!(
+            BalancerError::ChargeIsNotBalanced { difference: 2 }.get_description(),
+            "The charge cannot be balanced: it is off by +2 on the right."
+        );
+        assert_eq!(
+            BalancerError::ChargeIsNotBalanced { difference: -1 }.get_description(),
+            "The charge cannot be balanced: it is off by \u{2212}1 on the right."
+        );
+        assert_eq!(
+            BalancerError::AtomsAreNotBalanced { names: vec!["O".to_string()] }.get_description(),
+            "The atoms of O cannot be balanced."
+        );
+        assert_eq!(
+            BalancerError::IonsAreNotRecognized { names: vec!["CO2".to_string()] }
+                .get_description(),
+            "The ions of CO2 are not recognized."
+        );
+        assert_eq!(
+            BalancerError::ReactantsAreInsoluble { names: vec!["AgCl".to_string()] }
+                .get_description(),
+            "AgCl is insoluble and cannot react in solution."
+        );
+        assert_eq!(
+            BalancerError::ReactionIsImpossibleInThisDirection { names: vec!["O2".to_string()] }
+                .get_description(),
+            "The reaction as written is impossible in this direction: O2 is on the wrong side."
+        );
+        assert_eq!(
+            BalancerError::SpeciesAppearOnBothSides { names: vec!["MnO2".to_string()] }
+                .get_description(),
+            "The same species appear on both sides: MnO2; cancel them to balance the net equation."
+        );
+        assert_eq!(
+            BalancerError::SpeciesCancelOut.get_description(),
+            "All the species of a side cancel out."
+        );
+        assert_eq!(
+            BalancerError::StandardPotentialIsNotFound { couple: "ClO3{\u{2212}}/Cl2".to_string() }
+                .get_description(),
+            "No standard reduction potential is tabulated for ClO3{\u{2212}}/Cl2."
+        );
     }
 }
@@ -0,0 +1,95 @@
+use crate::entity::Entity;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BalancerWarning {
+    SpeciesMovedToOtherSide { entity: Entity, is_reactant: bool },
+    SpeciesOnBothSides { reactant: Entity, product: Entity },
+    /// The species has a zero coefficient and is dropped from the balanced equation.
+    /// The missing elements are the elements of the species not present in any other species.
+    ZeroCoefficient { entity: Entity, missing_elements: Vec<String> },
+}
+
+impl BalancerWarning {
+    /// Returns the description.
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::SpeciesMovedToOtherSide { entity, is_reactant } => format!(
+                "{} is moved to the {}: the reaction as written is impossible in this direction.",
+                entity.format(),
+                if *is_reactant { "reactants" } else { "products" },
+            ),
+            Self::SpeciesOnBothSides { reactant, product } => if reactant == product {
+                format!("{} appears on both sides.", reactant.format())
+            } else {
+                format!(
+                    "{} and {} have the same composition and appear on both sides.",
+                    reactant.format(),
+                    product.format(),
+                )
+            },
+            Self::ZeroCoefficient { entity, missing_elements } => if missing_elements.is_empty() {
+                format!(
+                    "{} has a zero coefficient: the equation balances without it.",
+                    entity.format(),
+                )
+            } else {
+                format!(
+                    "{} has a zero coefficient: {} {} not present in any other species.",
+                    entity.format(),
+                    missing_elements.join(", "),
+                    if missing_elements.len() == 1 { "is" } else { "are" },
+                )
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::balancer_warning::BalancerWarning;
+
+    #[test]
+    fn test_get_description() {
+        let warning = BalancerWarning::SpeciesMovedToOtherSide {
+            entity: "O2".parse().unwrap(),
+            is_reactant: false,
+        };
+        assert_eq!(
+            warning.get_description(),
+            "O2 is moved to the products: the reaction as written is impossible in this direction."
+        );
+
+        let warning = BalancerWarning::SpeciesOnBothSides {
+            reactant: "MnO2".parse().unwrap(),
+            product: "MnO2".parse().unwrap(),
+        };
+        assert_eq!(warning.get_description(), "MnO2 appears on both sides.");
+
+        let warning = BalancerWarning::SpeciesOnBothSides {
+            reactant: "CH3COOH".parse().unwrap(),
+            product: "C2H4O2".parse().unwrap(),
+        };
+        assert_eq!(
+            warning.get_description(),
+            "CH3COOH and C2H4O2 have the same composition and appear on both sides."
+        );
+
+        let warning = BalancerWarning::ZeroCoefficient {
+            entity: "N2".parse().unwrap(),
+            missing_elements: vec!["N".to_string()],
+        };
+        assert_eq!(
+            warning.get_description(),
+            "N2 has a zero coefficient: N is not present in any other species."
+        );
+
+        let warning = BalancerWarning::ZeroCoefficient {
+            entity: "H2O2".parse().unwrap(),
+            missing_elements: vec![],
+        };
+        assert_eq!(
+            warning.get_description(),
+            "H2O2 has a zero coefficient: the equation balances without it."
+        );
+    }
+}
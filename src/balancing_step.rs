@@ -0,0 +1,41 @@
+use crate::{matrix::Matrix, matrix_operation::MatrixOperation};
+
+/// A step of the matrix method, with the matrix after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BalancingStep {
+    /// The matrix is built from the compositions.
+    InitialMatrix { matrix: Matrix },
+    /// A row operation of the Gaussian elimination.
+    Elimination { operation: MatrixOperation, matrix: Matrix },
+    /// The coefficient of the column is set to 1 in the empty row.
+    FreeVariable { column_index: usize, matrix: Matrix },
+    /// The coefficients are scaled by the least common multiple of the diagonal cells.
+    Scaling { least_common_multiple: i32, coefficients: Vec<i32> },
+}
+
+impl BalancingStep {
+    /// Returns the matrix after the step, if any.
+    pub fn get_matrix(&self) -> Option<&Matrix> {
+        match self {
+            Self::InitialMatrix { matrix }
+            | Self::Elimination { matrix, .. }
+            | Self::FreeVariable { matrix, .. } => Some(matrix),
+            Self::Scaling { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{balancing_step::BalancingStep, matrix::Matrix};
+
+    #[test]
+    fn test_get_matrix() {
+        let matrix = Matrix::new(2, 2);
+        let step = BalancingStep::InitialMatrix { matrix: matrix.clone() };
+        assert_eq!(step.get_matrix(), Some(&matrix));
+
+        let step = BalancingStep::Scaling { least_common_multiple: 2, coefficients: vec![2, 1] };
+        assert_eq!(step.get_matrix(), None);
+    }
+}
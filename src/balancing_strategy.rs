@@ -0,0 +1,9 @@
+/// Strategy of finding the coefficients.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BalancingStrategy {
+    /// Gaussian elimination of the matrix of the element counts.
+    #[default]
+    Matrix,
+    /// Balancing by inspection, falling back to the matrix method when it gets stuck.
+    Inspection,
+}
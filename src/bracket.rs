@@ -0,0 +1,41 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Bracket {
+    #[default]
+    Round,
+    Square,
+}
+
+impl Bracket {
+    /// Returns the opening bracket.
+    pub fn get_opening(&self) -> &str {
+        match self {
+            Self::Round => "(",
+            Self::Square => "[",
+        }
+    }
+
+    /// Returns the closing bracket.
+    pub fn get_closing(&self) -> &str {
+        match self {
+            Self::Round => ")",
+            Self::Square => "]",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bracket::Bracket;
+
+    #[test]
+    fn test_get_opening() {
+        assert_eq!(Bracket::Round.get_opening(), "(");
+        assert_eq!(Bracket::Square.get_opening(), "[");
+    }
+
+    #[test]
+    fn test_get_closing() {
+        assert_eq!(Bracket::Round.get_closing(), ")");
+        assert_eq!(Bracket::Square.get_closing(), "]");
+    }
+}
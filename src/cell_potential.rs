@@ -0,0 +1,270 @@
+use itertools::Itertools;
+use crate::{
+    balanced_equation::BalancedEquation,
+    balancer::Balancer,
+    balancer_error::BalancerError,
+    entity::Entity,
+    equation::Equation,
+    half_reactions::HalfReactions,
+    medium::Medium,
+    phase::Phase,
+    redox_couple::RedoxCouple,
+    standard_potential::StandardPotential,
+};
+
+/// Faraday constant in coulombs per mole
+pub const FARADAY_CONSTANT: f64 = 96485.33212;
+/// Molar gas constant in joules per mole per kelvin
+pub const GAS_CONSTANT: f64 = 8.314462618;
+/// Standard temperature in kelvins
+pub const STANDARD_TEMPERATURE: f64 = 298.15;
+
+/// The galvanic cell of a redox equation: the tabulated half-reactions at the electrodes,
+/// the cell potential and the Gibbs energy.
+#[derive(Debug, Clone)]
+pub struct CellPotential {
+    /// Balanced equation
+    pub balanced_equation: BalancedEquation,
+    /// Reduction at the cathode
+    pub cathode: StandardPotential,
+    /// Reduction whose reverse is the oxidation at the anode
+    pub anode: StandardPotential,
+    /// Number of electrons transferred in the balanced equation
+    pub electrons_count: i32,
+}
+
+impl CellPotential {
+    /// Looks up the potentials of the redox couples of the equation in the medium and balances
+    /// the equation, completing it with the species of the medium if necessary.
+    pub fn new(equation: &Equation, medium: Medium) -> Result<Self, BalancerError> {
+        let half_reactions = HalfReactions::new(equation, medium)?;
+        let find = |couple: &RedoxCouple| StandardPotential::find(couple, medium)
+            .cloned()
+            .ok_or_else(|| BalancerError::StandardPotentialIsNotFound { couple: couple.format() });
+        let cathode = find(&half_reactions.reduction_couple)?;
+        let anode = find(&half_reactions.oxidation_couple)?;
+        let balanced_equation = Balancer::from_equation(equation.clone())
+            .with_completion(Some(medium))
+            .balance()?;
+        let electrons_count =
+            balanced_equation.get_redox_analysis().get_electrons_transferred().to_integer();
+
+        Ok(Self { balanced_equation, cathode, anode, electrons_count })
+    }
+
+    /// Returns the standard cell potential E°cell = E°cathode − E°anode in volts.
+    pub fn get_standard_potential(&self) -> f64 {
+        self.cathode.potential - self.anode.potential
+    }
+
+    /// Returns the standard Gibbs energy ΔG° = −nFE°cell in joules per mole of the reaction
+    /// as balanced.
+    pub fn get_standard_gibbs_energy(&self) -> f64 {
+        -f64::from(self.electrons_count) * FARADAY_CONSTANT * self.get_standard_potential()
+    }
+
+    /// Checks whether the reaction is spontaneous under standard conditions.
+    pub fn is_spontaneous(&self) -> bool {
+        self.get_standard_potential() > 0.0
+    }
+
+    /// Returns the reaction quotient from the concentrations in moles per liter of the
+    /// dissolved species and the partial pressures in bars of the gases.
+    ///
+    /// Solids, liquids, water and the species that are not listed have unit activity; an
+    /// activity of a species absent from the equation is an error.
+    pub fn get_reaction_quotient(
+        &self,
+        activities: &[(Entity, f64)],
+    ) -> Result<f64, BalancerError> {
+        let water: Entity = "H2O".parse().expect("Water is a valid formula.");
+        let equation = &self.balanced_equation.equation;
+        let entities: Vec<&Entity> = equation.reactants.iter().chain(&equation.products).collect();
+        let unmatched_names: Vec<String> = activities.iter()
+            .filter(|(x, _)| !entities.iter().any(|y| y.is_equivalent(x)))
+            .map(|(x, _)| x.format())
+            .collect();
+
+        if !unmatched_names.is_empty() {
+            return Err(BalancerError::ActivitiesAreNotMatched { names: unmatched_names });
+        }
+
+        let get_activity = |entity: &Entity| {
+            if entity.is_equivalent(&water)
+                || matches!(entity.phase, Some(Phase::Solid) | Some(Phase::Liquid)) {
+                return 1.0;
+            }
+
+            activities.iter().find(|(x, _)| x.is_equivalent(entity)).map_or(1.0, |(_, x)| *x)
+        };
+        let (reactants_coefficients, products_coefficients) =
+            self.balanced_equation.coefficients.split_at(equation.reactants.len());
+        let get_product = |entities: &[Entity], coefficients: &[i32]| entities.iter()
+            .zip(coefficients)
+            .map(|(x, &y)| get_activity(x).powi(y))
+            .product::<f64>();
+
+        Ok(get_product(&equation.products, products_coefficients)
+            / get_product(&equation.reactants, reactants_coefficients))
+    }
+
+    /// Returns the cell potential in volts at the temperature in kelvins by the Nernst
+    /// equation E = E° − (RT / nF) ln Q.
+    pub fn get_potential(
+        &self,
+        activities: &[(Entity, f64)],
+        temperature: f64,
+    ) -> Result<f64, BalancerError> {
+        Ok(self.get_standard_potential()
+            - GAS_CONSTANT * temperature / (f64::from(self.electrons_count) * FARADAY_CONSTANT)
+                * self.get_reaction_quotient(activities)?.ln())
+    }
+
+    /// Formats the number with the precision and a minus sign.
+    fn format_number(value: f64, precision: usize) -> String {
+        let text = format!("{value:.precision$}");
+
+        match text.strip_prefix('-') {
+            Some(x) => format!("\u{2212}{x}"),
+            None => text,
+        }
+    }
+
+    /// Formats the electrodes, the cell potential, the Gibbs energy and the spontaneity, one
+    /// per line.
+    pub fn format(&self) -> String {
+        let format_potential = |x: f64| if x < 0.0 {
+            format!("({})", Self::format_number(x, 2))
+        } else {
+            Self::format_number(x, 2)
+        };
+
+        [
+            format!(
+                "Cathode (reduction): {}, E\u{b0} = {} V",
+                self.cathode.half_reaction.format(),
+                Self::format_number(self.cathode.potential, 2),
+            ),
+            format!(
+                "Anode (oxidation): {}, E\u{b0} = {} V",
+                self.anode.half_reaction.format(),
+                Self::format_number(self.anode.potential, 2),
+            ),
+            format!(
+                "E\u{b0}cell = {} \u{2212} {} = {} V",
+                format_potential(self.cathode.potential),
+                format_potential(self.anode.potential),
+                Self::format_number(self.get_standard_potential(), 2),
+            ),
+            format!(
+                "\u{394}G\u{b0} = \u{2212}nFE\u{b0}cell = {} kJ/mol (n = {})",
+                Self::format_number(self.get_standard_gibbs_energy() / 1000.0, 1),
+                self.electrons_count,
+            ),
+            if self.is_spontaneous() {
+                "The reaction is spontaneous under standard conditions.".to_string()
+            } else {
+                "The reaction is not spontaneous under standard conditions.".to_string()
+            },
+        ].into_iter().join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        balancer_error::BalancerError,
+        cell_potential::{CellPotential, STANDARD_TEMPERATURE},
+        medium::Medium,
+        parser::Parser,
+    };
+
+    fn get_cell_potential(equation: &str, medium: Medium) -> Result<CellPotential, BalancerError> {
+        CellPotential::new(&Parser::new(equation).parse_equation().unwrap(), medium)
+    }
+
+    #[test]
+    fn test_new() {
+        let cell_potential = get_cell_potential("Zn + CuSO4 = ZnSO4 + Cu", Medium::Acidic).unwrap();
+        assert_eq!(cell_potential.cathode.potential, 0.34);
+        assert_eq!(cell_potential.anode.potential, -0.76);
+        assert_eq!(cell_potential.electrons_count, 2);
+
+        let cell_potential = get_cell_potential(
+            "KMnO4 + FeSO4 + H2SO4 = MnSO4 + Fe2(SO4)3 + K2SO4 + H2O",
+            Medium::Acidic,
+        ).unwrap();
+        assert_eq!(cell_potential.electrons_count, 10);
+
+        assert!(matches!(
+            get_cell_potential("Cl2 + NaOH = NaCl + NaClO3 + H2O", Medium::Basic),
+            Err(BalancerError::StandardPotentialIsNotFound { couple })
+                if couple == "ClO3{\u{2212}}/Cl2"
+        ));
+    }
+
+    #[test]
+    fn test_get_standard_potential() {
+        let cell_potential = get_cell_potential("Zn + CuSO4 = ZnSO4 + Cu", Medium::Acidic).unwrap();
+        assert!((cell_potential.get_standard_potential() - 1.10).abs() < 1e-9);
+
+        let cell_potential = get_cell_potential("Cu + ZnSO4 = CuSO4 + Zn", Medium::Acidic).unwrap();
+        assert!((cell_potential.get_standard_potential() + 1.10).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_get_standard_gibbs_energy() {
+        let cell_potential = get_cell_potential("Zn + CuSO4 = ZnSO4 + Cu", Medium::Acidic).unwrap();
+        assert!((cell_potential.get_standard_gibbs_energy() + 212267.7).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_is_spontaneous() {
+        assert!(get_cell_potential("Zn + CuSO4 = ZnSO4 + Cu", Medium::Acidic)
+            .unwrap()
+            .is_spontaneous());
+        assert!(!get_cell_potential("Cu + ZnSO4 = CuSO4 + Zn", Medium::Acidic)
+            .unwrap()
+            .is_spontaneous());
+    }
+
+    #[test]
+    fn test_get_reaction_quotient() {
+        let cell_potential =
+            get_cell_potential("Zn(s) + Cu{2+} = Zn{2+} + Cu(s)", Medium::Acidic).unwrap();
+        let activities = [("Zn{2+}".parse().unwrap(), 1.0), ("Cu{2+}".parse().unwrap(), 0.01)];
+        assert!((cell_potential.get_reaction_quotient(&activities).unwrap() - 100.0).abs() < 1e-9);
+
+        let cell_potential = get_cell_potential("Zn + CuSO4 = ZnSO4 + Cu", Medium::Acidic).unwrap();
+        let activities = [("Cu{2+}".parse().unwrap(), 0.01)];
+        assert!(matches!(
+            cell_potential.get_reaction_quotient(&activities),
+            Err(BalancerError::ActivitiesAreNotMatched { names }) if names == ["Cu{2+}"]
+        ));
+    }
+
+    #[test]
+    fn test_get_potential() {
+        let cell_potential =
+            get_cell_potential("Zn(s) + Cu{2+} = Zn{2+} + Cu(s)", Medium::Acidic).unwrap();
+        let activities = [("Zn{2+}".parse().unwrap(), 1.0), ("Cu{2+}".parse().unwrap(), 0.01)];
+        let potential = cell_potential.get_potential(&activities, STANDARD_TEMPERATURE).unwrap();
+        assert!((potential - 1.0408).abs() < 1e-4);
+
+        let potential = cell_potential.get_potential(&[], STANDARD_TEMPERATURE).unwrap();
+        assert!((potential - 1.10).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_format() {
+        let cell_potential = get_cell_potential("Zn + CuSO4 = ZnSO4 + Cu", Medium::Acidic).unwrap();
+        assert_eq!(
+            cell_potential.format(),
+            "Cathode (reduction): Cu{2+} + 2\u{a0}e\u{2212} = Cu, E\u{b0} = 0.34 V\n\
+                Anode (oxidation): Zn{2+} + 2\u{a0}e\u{2212} = Zn, E\u{b0} = \u{2212}0.76 V\n\
+                E\u{b0}cell = 0.34 \u{2212} (\u{2212}0.76) = 1.10 V\n\
+                \u{394}G\u{b0} = \u{2212}nFE\u{b0}cell = \u{2212}212.3 kJ/mol (n = 2)\n\
+                The reaction is spontaneous under standard conditions."
+        );
+    }
+}
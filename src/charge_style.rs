@@ -0,0 +1,87 @@
+/// Rendering of the charges of the ions, every one of which is parsed back into the same charge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChargeStyle {
+    /// The charge is enclosed in curly brackets: `Fe{3+}`, `Cl{−}`.
+    #[default]
+    Braces,
+    /// The charge is written in superscript: `Fe³⁺`, `Cl⁻`.
+    Superscript,
+    /// The charge follows a caret: `Fe^3+`, `Cl^-`.
+    Caret,
+}
+
+impl ChargeStyle {
+    /// Converts the digit to superscript.
+    fn get_superscript_digit(digit: char) -> char {
+        match digit {
+            '1' => '\u{b9}',
+            '2' => '\u{b2}',
+            '3' => '\u{b3}',
+            _ => char::from_u32(0x2070 + digit.to_digit(10).unwrap_or(0)).unwrap_or(digit),
+        }
+    }
+
+    /// Returns the value of the superscript digit, if it is one.
+    pub fn parse_superscript_digit(digit: char) -> Option<u8> {
+        match digit {
+            '\u{b9}' => Some(1),
+            '\u{b2}' => Some(2),
+            '\u{b3}' => Some(3),
+            '\u{2070}' | '\u{2074}'..='\u{2079}' => u8::try_from(digit as u32 - 0x2070).ok(),
+            _ => None,
+        }
+    }
+
+    /// Formats a non-zero charge, with the number omitted if it is 1.
+    pub fn format_charge(&self, charge: i8) -> String {
+        let number = if charge.abs() == 1 { String::new() } else { charge.abs().to_string() };
+
+        match self {
+            Self::Braces => format!("{{{number}{}}}", if charge > 0 { "+" } else { "\u{2212}" }),
+            Self::Superscript => format!(
+                "{}{}",
+                number.chars().map(Self::get_superscript_digit).collect::<String>(),
+                if charge > 0 { '\u{207a}' } else { '\u{207b}' },
+            ),
+            Self::Caret => format!("^{number}{}", if charge > 0 { "+" } else { "-" }),
+        }
+    }
+
+    /// Formats the electron.
+    pub fn format_electron(&self) -> String {
+        match self {
+            Self::Braces => "e\u{2212}".to_string(),
+            Self::Superscript | Self::Caret => format!("e{}", self.format_charge(-1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::charge_style::ChargeStyle;
+
+    #[test]
+    fn test_parse_superscript_digit() {
+        assert_eq!(ChargeStyle::parse_superscript_digit('\u{b2}'), Some(2));
+        assert_eq!(ChargeStyle::parse_superscript_digit('\u{2074}'), Some(4));
+        assert_eq!(ChargeStyle::parse_superscript_digit('4'), None);
+    }
+
+    #[test]
+    fn test_format_charge() {
+        assert_eq!(ChargeStyle::Braces.format_charge(1), "{+}");
+        assert_eq!(ChargeStyle::Braces.format_charge(-2), "{2\u{2212}}");
+        assert_eq!(ChargeStyle::Superscript.format_charge(3), "\u{b3}\u{207a}");
+        assert_eq!(ChargeStyle::Superscript.format_charge(-1), "\u{207b}");
+        assert_eq!(ChargeStyle::Superscript.format_charge(-14), "\u{b9}\u{2074}\u{207b}");
+        assert_eq!(ChargeStyle::Caret.format_charge(-1), "^-");
+        assert_eq!(ChargeStyle::Caret.format_charge(2), "^2+");
+    }
+
+    #[test]
+    fn test_format_electron() {
+        assert_eq!(ChargeStyle::Braces.format_electron(), "e\u{2212}");
+        assert_eq!(ChargeStyle::Superscript.format_electron(), "e\u{207b}");
+        assert_eq!(ChargeStyle::Caret.format_electron(), "e^-");
+    }
+}
@@ -0,0 +1,187 @@
+/// The number of atoms of every element and the charge of a formula.
+#[derive(Debug, Clone, Default)]
+pub struct Composition {
+    /// Counts of atoms by element names, in order of first appearance
+    pub elements: Vec<(String, i32)>,
+    /// Charge
+    pub charge: i32,
+}
+
+impl Composition {
+    /// Composition constructor.
+    pub fn new(elements: Vec<(String, i32)>, charge: i32) -> Self {
+        let mut composition = Self { elements: vec![], charge };
+
+        for (name, count) in elements {
+            composition.add_element(&name, count);
+        }
+
+        composition
+    }
+
+    /// Adds the atoms of element, removing the element when its count becomes zero.
+    pub fn add_element(&mut self, element_name: &str, count: i32) {
+        match self.elements.iter().position(|(x, _)| x == element_name) {
+            Some(i) => {
+                self.elements[i].1 += count;
+
+                if self.elements[i].1 == 0 {
+                    self.elements.remove(i);
+                }
+            },
+            None if count != 0 => self.elements.push((element_name.to_string(), count)),
+            None => {},
+        }
+    }
+
+    /// Counts the number of atoms of element by its name.
+    pub fn count_element_by_name(&self, element_name: &str) -> i32 {
+        self.elements.iter().find(|(x, _)| x == element_name).map_or(0, |(_, x)| *x)
+    }
+
+    /// Returns the names of elements.
+    pub fn get_elements_names(&self) -> Vec<&str> {
+        self.elements.iter().map(|(x, _)| x.as_str()).collect()
+    }
+
+    /// Formats the elements in Hill order: carbon, hydrogen, then the other elements
+    /// alphabetically, or all the elements alphabetically if there is no carbon.
+    pub fn format_hill(&self) -> String {
+        let mut elements: Vec<&(String, i32)> = self.elements.iter().collect();
+        let has_carbon = self.count_element_by_name("C") != 0;
+        let get_rank = |name: &str| match name {
+            "C" if has_carbon => 0,
+            "H" if has_carbon => 1,
+            _ => 2,
+        };
+
+        elements.sort_by(|(x, _), (y, _)| (get_rank(x), x).cmp(&(get_rank(y), y)));
+
+        elements.iter().map(|(name, count)| {
+            if *count == 1 { name.clone() } else { [name.clone(), count.to_string()].join("") }
+        }).collect()
+    }
+
+    /// Checks whether there are no atoms and no charge.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty() && self.charge == 0
+    }
+
+    /// Returns the sum of two compositions.
+    pub fn add(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+
+        for (name, count) in &other.elements {
+            result.add_element(name, *count);
+        }
+
+        result.charge += other.charge;
+
+        result
+    }
+
+    /// Returns the composition multiplied by a factor.
+    pub fn scale(&self, factor: i32) -> Self {
+        Self::new(
+            self.elements.iter().map(|(name, count)| (name.clone(), count * factor)).collect(),
+            self.charge * factor,
+        )
+    }
+
+    /// Returns the difference of two compositions.
+    pub fn subtract(&self, other: &Self) -> Self {
+        self.add(&other.scale(-1))
+    }
+}
+
+impl PartialEq for Composition {
+    fn eq(&self, other: &Self) -> bool {
+        self.subtract(other).is_empty()
+    }
+}
+
+impl Eq for Composition {}
+
+#[cfg(test)]
+mod tests {
+    use crate::composition::Composition;
+
+    /// Returns the composition of water.
+    fn get_water() -> Composition {
+        Composition::new(vec![("H".to_string(), 2), ("O".to_string(), 1)], 0)
+    }
+
+    #[test]
+    fn test_new() {
+        let composition = Composition::new(
+            vec![("H".to_string(), 1), ("O".to_string(), 1), ("H".to_string(), 1)],
+            -1,
+        );
+        assert_eq!(composition.elements, [("H".to_string(), 2), ("O".to_string(), 1)]);
+        assert_eq!(composition.charge, -1);
+    }
+
+    #[test]
+    fn test_add_element() {
+        let mut composition = get_water();
+        composition.add_element("O", -1);
+        assert_eq!(composition.get_elements_names(), ["H"]);
+
+        composition.add_element("C", 0);
+        assert_eq!(composition.get_elements_names(), ["H"]);
+    }
+
+    #[test]
+    fn test_count_element_by_name() {
+        assert_eq!(get_water().count_element_by_name("H"), 2);
+        assert_eq!(get_water().count_element_by_name("C"), 0);
+    }
+
+    #[test]
+    fn test_format_hill() {
+        assert_eq!(get_water().format_hill(), "H2O");
+
+        let acetic_acid = Composition::new(
+            vec![("C".to_string(), 2), ("O".to_string(), 2), ("H".to_string(), 4)],
+            0,
+        );
+        assert_eq!(acetic_acid.format_hill(), "C2H4O2");
+
+        let sulfate = Composition::new(
+            vec![("Fe".to_string(), 2), ("S".to_string(), 3), ("O".to_string(), 12)],
+            0,
+        );
+        assert_eq!(sulfate.format_hill(), "Fe2O12S3");
+
+        let chloride = Composition::new(vec![("Na".to_string(), 1), ("Cl".to_string(), 1)], 0);
+        assert_eq!(chloride.format_hill(), "ClNa");
+    }
+
+    #[test]
+    fn test_is_empty() {
+        assert!(Composition::default().is_empty());
+        assert!(!get_water().is_empty());
+        assert!(!Composition::new(vec![], 1).is_empty());
+    }
+
+    #[test]
+    fn test_add() {
+        let hydroxide = Composition::new(vec![("O".to_string(), 1), ("H".to_string(), 1)], -1);
+        let proton = Composition::new(vec![("H".to_string(), 1)], 1);
+        assert_eq!(hydroxide.add(&proton), get_water());
+        assert_eq!(hydroxide.add(&proton).elements, [("O".to_string(), 1), ("H".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(get_water().scale(3).count_element_by_name("H"), 6);
+        assert!(get_water().scale(0).is_empty());
+    }
+
+    #[test]
+    fn test_subtract() {
+        assert!(get_water().subtract(&get_water()).is_empty());
+        assert_eq!(get_water().subtract(&Default::default()), get_water());
+        assert_ne!(get_water().subtract(&get_water().scale(2)), get_water());
+    }
+}
@@ -0,0 +1,229 @@
+use num::integer::gcd;
+use crate::{composition::Composition, element_data::ElementData, entity::Entity};
+
+/// Anions of the salts, acids and bases, the polyatomic ions before the ions they contain.
+const ANIONS: [&str; 27] = [
+    "Cr2O7{2-}", "CrO4{2-}", "MnO4{-}", "C2O4{2-}", "S2O3{2-}", "SO4{2-}", "SO3{2-}", "NO3{-}",
+    "NO2{-}", "PO4{3-}", "CO3{2-}", "HCO3{-}", "ClO4{-}", "ClO3{-}", "ClO2{-}", "ClO{-}",
+    "BrO3{-}", "IO3{-}", "CH3COO{-}", "SCN{-}", "CN{-}", "OH{-}", "F{-}", "Cl{-}", "Br{-}",
+    "I{-}", "S{2-}",
+];
+
+/// The ions of a salt, an acid or a base in solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dissociation {
+    /// Cation
+    pub cation: Entity,
+    /// Number of cations in a formula unit
+    pub cations_count: i32,
+    /// Anion
+    pub anion: Entity,
+    /// Number of anions in a formula unit
+    pub anions_count: i32,
+    /// Number of water molecules of the hydrate parts in a formula unit
+    pub waters_count: i32,
+}
+
+impl Dissociation {
+    /// Splits the neutral compound into a cation and an anion written in its formula, if the
+    /// remainder after the anions is a metal, hydrogen or ammonium with a possible charge, and
+    /// releases the water of its hydrate parts.
+    pub fn new(entity: &Entity) -> Option<Self> {
+        let formula = Entity::new(entity.items.clone(), 0);
+        let composition = formula.get_composition();
+        let water_composition = Composition::new(
+            vec![("H".to_string(), 2), ("O".to_string(), 1)],
+            0,
+        );
+
+        if entity.charge != 0 || composition == water_composition {
+            return None;
+        }
+
+        let waters_count = entity.hydrates.iter()
+            .map(|x| (Entity::new(x.items.clone(), 0).get_composition() == water_composition)
+                .then_some(i32::from(x.count)))
+            .sum::<Option<i32>>()?;
+
+        ANIONS.iter().find_map(|x| {
+            let anion = x.parse::<Entity>().expect("Anions are valid formulas.");
+
+            if !formula.format().contains(&Entity::new(anion.items.clone(), 0).format()) {
+                return None;
+            }
+
+            let anion_composition = anion.get_composition();
+            let anions_count = anion_composition.elements.iter()
+                .map(|(name, count)| composition.count_element_by_name(name) / count)
+                .min()
+                .unwrap_or(0);
+
+            if anions_count == 0 {
+                return None;
+            }
+
+            let remainder = composition.subtract(&anion_composition.scale(anions_count));
+            let (cation, cations_count) =
+                Self::find_cation(&remainder, -anion_composition.charge * anions_count)?;
+
+            Some(Self { cation, cations_count, anion, anions_count, waters_count })
+        })
+    }
+
+    /// Returns the cation with its count if the remainder is a metal, hydrogen or ammonium
+    /// whose charge makes up the charge of the anions.
+    fn find_cation(remainder: &Composition, charge: i32) -> Option<(Entity, i32)> {
+        let ammonium_count = remainder.count_element_by_name("N");
+
+        if remainder.elements.len() == 2
+            && ammonium_count == charge
+            && remainder.count_element_by_name("H") == 4 * ammonium_count {
+            return Some(("NH4{+}".parse().expect("Ammonium is a valid formula."), charge));
+        }
+
+        let [(symbol, count)] = remainder.elements.as_slice() else {
+            return None;
+        };
+        let element_data = ElementData::find(symbol)?;
+
+        if charge % count != 0 || !element_data.is_metal && symbol != "H" {
+            return None;
+        }
+
+        let ion_charge = charge / count;
+        let is_possible = if element_data.is_alkali_metal() || symbol == "H" {
+            ion_charge == 1
+        } else if element_data.is_alkaline_earth_metal() {
+            ion_charge == 2
+        } else {
+            (1..=4).contains(&ion_charge)
+        };
+
+        if !is_possible {
+            return None;
+        }
+
+        let formula = if ion_charge == 1 {
+            format!("{symbol}{{+}}")
+        } else {
+            format!("{symbol}{{{ion_charge}+}}")
+        };
+
+        formula.parse().ok().map(|x| (x, *count))
+    }
+
+    /// Combines the cation and the anion in the ratio that makes a neutral compound.
+    pub fn from_ions(cation: Entity, anion: Entity) -> Self {
+        let cation_charge = i32::from(cation.charge);
+        let anion_charge = -i32::from(anion.charge);
+        let divisor = gcd(cation_charge, anion_charge);
+
+        Self {
+            cation,
+            cations_count: anion_charge / divisor,
+            anion,
+            anions_count: cation_charge / divisor,
+            waters_count: 0,
+        }
+    }
+
+    /// Returns the neutral compound of the ions, with the polyatomic ions in brackets.
+    pub fn get_compound(&self) -> Entity {
+        let formula: String = [(&self.cation, self.cations_count), (&self.anion, self.anions_count)]
+            .into_iter()
+            .map(|(ion, count)| {
+                let ion_formula = Entity::new(ion.items.clone(), 0);
+                let composition = ion_formula.get_composition();
+                let is_polyatomic = composition.elements.len() > 1
+                    || composition.elements.iter().any(|(_, x)| *x > 1);
+
+                match count {
+                    1 => ion_formula.format(),
+                    _ if is_polyatomic => format!("({}){count}", ion_formula.format()),
+                    _ => format!("{}{count}", ion_formula.format()),
+                }
+            })
+            .collect();
+
+        formula.parse().expect("Compounds of ions are valid formulas.")
+    }
+
+    /// Returns the cation and the anion with their counts in a formula unit.
+    pub fn get_ions(&self) -> [(Entity, i32); 2] {
+        [(self.cation.clone(), self.cations_count), (self.anion.clone(), self.anions_count)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dissociation::Dissociation, entity::Entity};
+
+    fn get_ions(formula: &str) -> Option<Vec<(String, i32)>> {
+        Dissociation::new(&formula.parse::<Entity>().unwrap()).map(|x| x.get_ions()
+            .into_iter()
+            .map(|(entity, count)| (entity.to_string(), count))
+            .collect())
+    }
+
+    fn get_waters_count(formula: &str) -> Option<i32> {
+        Dissociation::new(&formula.parse::<Entity>().unwrap()).map(|x| x.waters_count)
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            get_ions("Fe2(SO4)3"),
+            Some(vec![("Fe{3+}".to_string(), 2), ("SO4{2-}".to_string(), 3)])
+        );
+        assert_eq!(
+            get_ions("KMnO4"),
+            Some(vec![("K{+}".to_string(), 1), ("MnO4{-}".to_string(), 1)])
+        );
+        assert_eq!(
+            get_ions("(NH4)2SO4"),
+            Some(vec![("NH4{+}".to_string(), 2), ("SO4{2-}".to_string(), 1)])
+        );
+        assert_eq!(
+            get_ions("HNO3"),
+            Some(vec![("H{+}".to_string(), 1), ("NO3{-}".to_string(), 1)])
+        );
+        assert_eq!(
+            get_ions("NaHCO3"),
+            Some(vec![("Na{+}".to_string(), 1), ("HCO3{-}".to_string(), 1)])
+        );
+        assert_eq!(
+            get_ions("CuSO4*5H2O"),
+            Some(vec![("Cu{2+}".to_string(), 1), ("SO4{2-}".to_string(), 1)])
+        );
+        assert_eq!(get_waters_count("CuSO4*5H2O"), Some(5));
+        assert_eq!(get_waters_count("CuSO4"), Some(0));
+        assert_eq!(get_waters_count("CuSO4*NH3"), None);
+        assert_eq!(get_ions("H2O"), None);
+        assert_eq!(get_ions("MnO2"), None);
+        assert_eq!(get_ions("C6H12O6"), None);
+        assert_eq!(get_ions("SO4{2-}"), None);
+    }
+
+    #[test]
+    fn test_from_ions() {
+        let dissociation = Dissociation::from_ions(
+            "Fe{3+}".parse().unwrap(),
+            "SO4{2-}".parse().unwrap(),
+        );
+        assert_eq!(dissociation.cations_count, 2);
+        assert_eq!(dissociation.anions_count, 3);
+    }
+
+    #[test]
+    fn test_get_compound() {
+        let get_compound = |cation: &str, anion: &str| Dissociation::from_ions(
+            cation.parse().unwrap(),
+            anion.parse().unwrap(),
+        ).get_compound().format();
+        assert_eq!(get_compound("Fe{3+}", "SO4{2-}"), "Fe2(SO4)3");
+        assert_eq!(get_compound("Ag{+}", "Cl{-}"), "AgCl");
+        assert_eq!(get_compound("Ca{2+}", "Cl{-}"), "CaCl2");
+        assert_eq!(get_compound("NH4{+}", "PO4{3-}"), "(NH4)3PO4");
+        assert_eq!(get_compound("Pb{2+}", "CO3{2-}"), "PbCO3");
+    }
+}
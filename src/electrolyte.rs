@@ -0,0 +1,116 @@
+use std::sync::OnceLock;
+use crate::{
+    composition::Composition,
+    dissociation::Dissociation,
+    entity::Entity,
+    phase::Phase,
+    solubility::Solubility,
+};
+
+/// Bundled formulas of the strong acids and bases.
+const STRONG_ACIDS_AND_BASES: &str = include_str!("../data/strong_acids_and_bases.txt");
+
+/// Compositions of the strong acids and bases of the bundled table, parsed on the first use.
+static STRONG_COMPOSITIONS: OnceLock<Vec<Composition>> = OnceLock::new();
+
+/// The behavior of a species in water, which decides whether it is written as ions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Electrolyte {
+    Insoluble,
+    Nonelectrolyte,
+    Strong,
+    Weak,
+}
+
+impl Electrolyte {
+    /// Classifies the species: a solid is insoluble, a gas, a liquid or a compound without
+    /// ions is a nonelectrolyte, an acid or a base is strong if it is tabulated, and a salt
+    /// is strong if it is soluble.
+    pub fn classify(entity: &Entity) -> Self {
+        match entity.phase {
+            Some(Phase::Solid) => return Self::Insoluble,
+            Some(Phase::Gas | Phase::Liquid) => return Self::Nonelectrolyte,
+            Some(Phase::Aqueous) | None => {},
+        }
+
+        let Some(dissociation) = Dissociation::new(entity) else {
+            return Self::Nonelectrolyte;
+        };
+        let [hydrogen_ion, hydroxide_ion] = ["H{+}", "OH{-}"]
+            .map(|x| x.parse::<Entity>().expect("Ions are valid formulas."));
+        let is_acid = dissociation.cation == hydrogen_ion;
+        let is_base = dissociation.anion == hydroxide_ion;
+
+        if (is_acid || is_base) && Self::is_strong_acid_or_base(entity) {
+            return Self::Strong;
+        }
+
+        match Solubility::find(&dissociation.cation, &dissociation.anion) {
+            _ if is_acid => Self::Weak,
+            Solubility::Soluble if is_base => Self::Weak,
+            Solubility::Soluble => Self::Strong,
+            Solubility::Insoluble => Self::Insoluble,
+        }
+    }
+
+    /// Checks whether the compound is a tabulated strong acid or base.
+    fn is_strong_acid_or_base(entity: &Entity) -> bool {
+        let composition = Entity::new(entity.items.clone(), 0).get_composition();
+
+        STRONG_COMPOSITIONS.get_or_init(|| STRONG_ACIDS_AND_BASES.lines()
+            .map(str::trim)
+            .filter(|x| !x.is_empty() && !x.starts_with('#'))
+            .map(|x| x.parse::<Entity>().expect("Acids and bases are valid formulas.")
+                .get_composition())
+            .collect())
+            .contains(&composition)
+    }
+
+    /// Checks whether the species is written as its ions in an ionic equation.
+    pub fn is_dissociated(&self) -> bool {
+        *self == Self::Strong
+    }
+
+    /// Returns the description.
+    pub fn get_description(&self) -> &str {
+        match self {
+            Self::Insoluble => "An insoluble compound is written as a formula.",
+            Self::Nonelectrolyte => "A nonelectrolyte is written as a formula.",
+            Self::Strong => "A strong electrolyte is written as its ions.",
+            Self::Weak => "A weak electrolyte is written as a formula.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{electrolyte::Electrolyte, entity::Entity};
+
+    fn classify(formula: &str) -> Electrolyte {
+        Electrolyte::classify(&formula.parse::<Entity>().unwrap())
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("NaCl"), Electrolyte::Strong);
+        assert_eq!(classify("AgNO3(aq)"), Electrolyte::Strong);
+        assert_eq!(classify("AgCl"), Electrolyte::Insoluble);
+        assert_eq!(classify("NaCl(s)"), Electrolyte::Insoluble);
+        assert_eq!(classify("HCl"), Electrolyte::Strong);
+        assert_eq!(classify("HCl(g)"), Electrolyte::Nonelectrolyte);
+        assert_eq!(classify("H2SO4"), Electrolyte::Strong);
+        assert_eq!(classify("CH3COOH"), Electrolyte::Weak);
+        assert_eq!(classify("HF"), Electrolyte::Weak);
+        assert_eq!(classify("Ba(OH)2"), Electrolyte::Strong);
+        assert_eq!(classify("NH4OH"), Electrolyte::Weak);
+        assert_eq!(classify("Fe(OH)3"), Electrolyte::Insoluble);
+        assert_eq!(classify("H2O"), Electrolyte::Nonelectrolyte);
+        assert_eq!(classify("C6H12O6"), Electrolyte::Nonelectrolyte);
+    }
+
+    #[test]
+    fn test_is_dissociated() {
+        assert!(Electrolyte::Strong.is_dissociated());
+        assert!(!Electrolyte::Weak.is_dissociated());
+    }
+}
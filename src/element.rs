@@ -1,31 +1,45 @@
-use std::collections::{HashSet, hash_map::RandomState};
-use crate::item::Item;
+use std::{collections::{HashSet, hash_map::RandomState}, hash::{Hash, Hasher}};
+use crate::{composition::Composition, span::Span};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Element {
     /// Name
     pub name: String,
     /// Count
     pub count: u8,
+    /// Span in the input
+    pub span: Span,
 }
 
 impl Element {
     /// Element constructor.
     pub fn new(name: String, count: u8) -> Self {
-        Self { name, count }
+        Self { name, count, span: Span::default() }
+    }
+
+    /// Sets the span in the input.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
     }
-}
 
-impl Item for Element {
-    fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>) {
+    /// Adds the element name to set of element names.
+    pub fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>) {
         elements_names.insert(self.name.clone());
     }
 
-    fn count_element_by_name(&self, element_name: &str) -> u32 {
+    /// Counts the number of atoms of element by its name.
+    pub fn count_element_by_name(&self, element_name: &str) -> u32 {
         if self.name == element_name { u32::from(self.count) } else { 0 }
     }
 
-    fn format(&self) -> String {
+    /// Adds the atoms of element multiplied by the factor to the composition.
+    pub fn add_to_composition(&self, composition: &mut Composition, factor: i32) {
+        composition.add_element(&self.name, i32::from(self.count) * factor);
+    }
+
+    /// Formats an element.
+    pub fn format(&self) -> String {
         let mut result = self.name.clone();
 
         if self.count != 1 {
@@ -34,12 +48,36 @@ impl Item for Element {
 
         result
     }
+
+    /// Formats an element in LaTeX.
+    pub fn format_latex(&self) -> String {
+        if self.count != 1 {
+            format!("{}_{{{}}}", self.name, self.count)
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.count == other.count
+    }
+}
+
+impl Eq for Element {}
+
+impl Hash for Element {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.count.hash(state);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use crate::{element::Element, item::Item};
+    use crate::{composition::Composition, element::Element, span::Span};
 
     #[test]
     fn test_new() {
@@ -48,6 +86,13 @@ mod tests {
         assert_eq!(element.count, 2);
     }
 
+    #[test]
+    fn test_with_span() {
+        let element = Element::new("H".to_string(), 2).with_span(Span::new(3, 5));
+        assert_eq!(element.span, Span::new(3, 5));
+        assert_eq!(element, Element::new("H".to_string(), 2));
+    }
+
     #[test]
     fn test_add_to_elements_names() {
         let mut elements_names = HashSet::new();
@@ -73,9 +118,22 @@ mod tests {
         assert_eq!(element.count_element_by_name("O"), 0);
     }
 
+    #[test]
+    fn test_add_to_composition() {
+        let mut composition = Composition::default();
+        Element::new("H".to_string(), 2).add_to_composition(&mut composition, 3);
+        assert_eq!(composition.count_element_by_name("H"), 6);
+    }
+
     #[test]
     fn test_format() {
         assert_eq!(Element::new("H".to_string(), 2).format(), "H2");
         assert_eq!(Element::new("O".to_string(), 1).format(), "O");
     }
+
+    #[test]
+    fn test_format_latex() {
+        assert_eq!(Element::new("H".to_string(), 12).format_latex(), "H_{12}");
+        assert_eq!(Element::new("O".to_string(), 1).format_latex(), "O");
+    }
 }
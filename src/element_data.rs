@@ -0,0 +1,198 @@
+/// Properties of a chemical element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElementData {
+    /// Symbol
+    pub symbol: &'static str,
+    /// Group in the periodic table, 3 for the lanthanides and actinides
+    pub group: u8,
+    /// Electronegativity on the Pauling scale, if it is known
+    pub electronegativity: Option<f64>,
+    /// Whether the element is a metal
+    pub is_metal: bool,
+}
+
+/// Elements in order of their atomic numbers.
+const ELEMENTS: [ElementData; 118] = [
+    ElementData::new("H", 1, Some(2.20), false),
+    ElementData::new("He", 18, None, false),
+    ElementData::new("Li", 1, Some(0.98), true),
+    ElementData::new("Be", 2, Some(1.57), true),
+    ElementData::new("B", 13, Some(2.04), false),
+    ElementData::new("C", 14, Some(2.55), false),
+    ElementData::new("N", 15, Some(3.04), false),
+    ElementData::new("O", 16, Some(3.44), false),
+    ElementData::new("F", 17, Some(3.98), false),
+    ElementData::new("Ne", 18, None, false),
+    ElementData::new("Na", 1, Some(0.93), true),
+    ElementData::new("Mg", 2, Some(1.31), true),
+    ElementData::new("Al", 13, Some(1.61), true),
+    ElementData::new("Si", 14, Some(1.90), false),
+    ElementData::new("P", 15, Some(2.19), false),
+    ElementData::new("S", 16, Some(2.58), false),
+    ElementData::new("Cl", 17, Some(3.16), false),
+    ElementData::new("Ar", 18, None, false),
+    ElementData::new("K", 1, Some(0.82), true),
+    ElementData::new("Ca", 2, Some(1.00), true),
+    ElementData::new("Sc", 3, Some(1.36), true),
+    ElementData::new("Ti", 4, Some(1.54), true),
+    ElementData::new("V", 5, Some(1.63), true),
+    ElementData::new("Cr", 6, Some(1.66), true),
+    ElementData::new("Mn", 7, Some(1.55), true),
+    ElementData::new("Fe", 8, Some(1.83), true),
+    ElementData::new("Co", 9, Some(1.88), true),
+    ElementData::new("Ni", 10, Some(1.91), true),
+    ElementData::new("Cu", 11, Some(1.90), true),
+    ElementData::new("Zn", 12, Some(1.65), true),
+    ElementData::new("Ga", 13, Some(1.81), true),
+    ElementData::new("Ge", 14, Some(2.01), true),
+    ElementData::new("As", 15, Some(2.18), false),
+    ElementData::new("Se", 16, Some(2.55), false),
+    ElementData::new("Br", 17, Some(2.96), false),
+    ElementData::new("Kr", 18, Some(3.00), false),
+    ElementData::new("Rb", 1, Some(0.82), true),
+    ElementData::new("Sr", 2, Some(0.95), true),
+    ElementData::new("Y", 3, Some(1.22), true),
+    ElementData::new("Zr", 4, Some(1.33), true),
+    ElementData::new("Nb", 5, Some(1.60), true),
+    ElementData::new("Mo", 6, Some(2.16), true),
+    ElementData::new("Tc", 7, Some(1.90), true),
+    ElementData::new("Ru", 8, Some(2.20), true),
+    ElementData::new("Rh", 9, Some(2.28), true),
+    ElementData::new("Pd", 10, Some(2.20), true),
+    ElementData::new("Ag", 11, Some(1.93), true),
+    ElementData::new("Cd", 12, Some(1.69), true),
+    ElementData::new("In", 13, Some(1.78), true),
+    ElementData::new("Sn", 14, Some(1.96), true),
+    ElementData::new("Sb", 15, Some(2.05), true),
+    ElementData::new("Te", 16, Some(2.10), false),
+    ElementData::new("I", 17, Some(2.66), false),
+    ElementData::new("Xe", 18, Some(2.60), false),
+    ElementData::new("Cs", 1, Some(0.79), true),
+    ElementData::new("Ba", 2, Some(0.89), true),
+    ElementData::new("La", 3, Some(1.10), true),
+    ElementData::new("Ce", 3, Some(1.12), true),
+    ElementData::new("Pr", 3, Some(1.13), true),
+    ElementData::new("Nd", 3, Some(1.14), true),
+    ElementData::new("Pm", 3, Some(1.13), true),
+    ElementData::new("Sm", 3, Some(1.17), true),
+    ElementData::new("Eu", 3, Some(1.20), true),
+    ElementData::new("Gd", 3, Some(1.20), true),
+    ElementData::new("Tb", 3, Some(1.10), true),
+    ElementData::new("Dy", 3, Some(1.22), true),
+    ElementData::new("Ho", 3, Some(1.23), true),
+    ElementData::new("Er", 3, Some(1.24), true),
+    ElementData::new("Tm", 3, Some(1.25), true),
+    ElementData::new("Yb", 3, Some(1.10), true),
+    ElementData::new("Lu", 3, Some(1.27), true),
+    ElementData::new("Hf", 4, Some(1.30), true),
+    ElementData::new("Ta", 5, Some(1.50), true),
+    ElementData::new("W", 6, Some(2.36), true),
+    ElementData::new("Re", 7, Some(1.90), true),
+    ElementData::new("Os", 8, Some(2.20), true),
+    ElementData::new("Ir", 9, Some(2.20), true),
+    ElementData::new("Pt", 10, Some(2.28), true),
+    ElementData::new("Au", 11, Some(2.54), true),
+    ElementData::new("Hg", 12, Some(2.00), true),
+    ElementData::new("Tl", 13, Some(1.62), true),
+    ElementData::new("Pb", 14, Some(2.33), true),
+    ElementData::new("Bi", 15, Some(2.02), true),
+    ElementData::new("Po", 16, Some(2.00), true),
+    ElementData::new("At", 17, Some(2.20), false),
+    ElementData::new("Rn", 18, Some(2.20), false),
+    ElementData::new("Fr", 1, Some(0.70), true),
+    ElementData::new("Ra", 2, Some(0.90), true),
+    ElementData::new("Ac", 3, Some(1.10), true),
+    ElementData::new("Th", 3, Some(1.30), true),
+    ElementData::new("Pa", 3, Some(1.50), true),
+    ElementData::new("U", 3, Some(1.38), true),
+    ElementData::new("Np", 3, Some(1.36), true),
+    ElementData::new("Pu", 3, Some(1.28), true),
+    ElementData::new("Am", 3, Some(1.13), true),
+    ElementData::new("Cm", 3, Some(1.28), true),
+    ElementData::new("Bk", 3, Some(1.30), true),
+    ElementData::new("Cf", 3, Some(1.30), true),
+    ElementData::new("Es", 3, Some(1.30), true),
+    ElementData::new("Fm", 3, Some(1.30), true),
+    ElementData::new("Md", 3, Some(1.30), true),
+    ElementData::new("No", 3, Some(1.30), true),
+    ElementData::new("Lr", 3, Some(1.30), true),
+    ElementData::new("Rf", 4, None, true),
+    ElementData::new("Db", 5, None, true),
+    ElementData::new("Sg", 6, None, true),
+    ElementData::new("Bh", 7, None, true),
+    ElementData::new("Hs", 8, None, true),
+    ElementData::new("Mt", 9, None, true),
+    ElementData::new("Ds", 10, None, true),
+    ElementData::new("Rg", 11, None, true),
+    ElementData::new("Cn", 12, None, true),
+    ElementData::new("Nh", 13, None, true),
+    ElementData::new("Fl", 14, None, true),
+    ElementData::new("Mc", 15, None, true),
+    ElementData::new("Lv", 16, None, true),
+    ElementData::new("Ts", 17, None, false),
+    ElementData::new("Og", 18, None, false),
+];
+
+impl ElementData {
+    /// ElementData constructor.
+    const fn new(
+        symbol: &'static str,
+        group: u8,
+        electronegativity: Option<f64>,
+        is_metal: bool,
+    ) -> Self {
+        Self { symbol, group, electronegativity, is_metal }
+    }
+
+    /// Returns the properties of the element by its symbol.
+    pub fn find(symbol: &str) -> Option<&'static Self> {
+        ELEMENTS.iter().find(|x| x.symbol == symbol)
+    }
+
+    /// Returns the atomic number.
+    pub fn get_atomic_number(&self) -> usize {
+        ELEMENTS.iter().position(|x| x.symbol == self.symbol).map_or(0, |x| x + 1)
+    }
+
+    /// Checks whether the element is an alkali metal.
+    pub fn is_alkali_metal(&self) -> bool {
+        self.group == 1 && self.is_metal
+    }
+
+    /// Checks whether the element is an alkaline earth metal.
+    pub fn is_alkaline_earth_metal(&self) -> bool {
+        self.group == 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::element_data::ElementData;
+
+    #[test]
+    fn test_find() {
+        let element_data = ElementData::find("Fe").unwrap();
+        assert_eq!(element_data.group, 8);
+        assert_eq!(element_data.electronegativity, Some(1.83));
+        assert!(element_data.is_metal);
+        assert_eq!(ElementData::find("Xx"), None);
+    }
+
+    #[test]
+    fn test_get_atomic_number() {
+        assert_eq!(ElementData::find("H").unwrap().get_atomic_number(), 1);
+        assert_eq!(ElementData::find("Og").unwrap().get_atomic_number(), 118);
+    }
+
+    #[test]
+    fn test_is_alkali_metal() {
+        assert!(ElementData::find("Na").unwrap().is_alkali_metal());
+        assert!(!ElementData::find("H").unwrap().is_alkali_metal());
+    }
+
+    #[test]
+    fn test_is_alkaline_earth_metal() {
+        assert!(ElementData::find("Ca").unwrap().is_alkaline_earth_metal());
+        assert!(!ElementData::find("Zn").unwrap().is_alkaline_earth_metal());
+    }
+}
@@ -1,92 +1,438 @@
 use num::abs;
-use std::collections::{HashSet, hash_map::RandomState};
-use crate::item::Item;
+use std::{
+    collections::{HashSet, hash_map::RandomState},
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+use crate::{
+    charge_style::ChargeStyle,
+    composition::Composition,
+    hydrate::Hydrate,
+    item::Item,
+    notation::Notation,
+    oxidation_states::OxidationStates,
+    parser::Parser,
+    parser_error::ParserError,
+    phase::Phase,
+    span::Span,
+    visitor::Visitor,
+};
 
+#[derive(Debug, Clone)]
 pub struct Entity {
     /// Items
-    pub items: Vec<Box<dyn Item>>,
+    pub items: Vec<Item>,
+    /// Hydrate parts
+    pub hydrates: Vec<Hydrate>,
     /// Charge
     pub charge: i8,
+    /// Phase
+    pub phase: Option<Phase>,
+    /// Span in the input
+    pub span: Span,
 }
 
 impl Entity {
     /// Entity constructor.
-    pub fn new(items: Vec<Box<dyn Item>>, charge: i8) -> Self {
+    pub fn new(items: Vec<Item>, charge: i8) -> Self {
         debug_assert!(!items.is_empty() || charge == -1, "Invalid entity.");
 
-        Self { items, charge }
+        Self { items, hydrates: vec![], charge, phase: None, span: Span::default() }
+    }
+
+    /// Sets the hydrate parts.
+    pub fn with_hydrates(mut self, hydrates: Vec<Hydrate>) -> Self {
+        self.hydrates = hydrates;
+        self
+    }
+
+    /// Sets the phase.
+    pub fn with_phase(mut self, phase: Option<Phase>) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Sets the span in the input.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
     }
-}
 
-impl Item for Entity {
-    fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>) {
-        elements_names.insert("e".to_owned());
+    /// Checks whether the entity is an electron.
+    pub fn is_electron(&self) -> bool {
+        self.items.is_empty() && self.hydrates.is_empty() && self.charge == -1
+    }
 
+    /// Accepts a visitor.
+    pub fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_entity(self);
+    }
+
+    /// Adds the element name to set of element names.
+    pub fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>) {
         for x in &self.items {
             x.add_to_elements_names(elements_names);
         }
-    }
 
-    fn count_element_by_name(&self, element_name: &str) -> u32 {
-        if element_name == "e" {
-            return -self.charge as u32;
+        for x in &self.hydrates {
+            x.add_to_elements_names(elements_names);
         }
+    }
 
+    /// Counts the number of atoms of element by its name.
+    pub fn count_element_by_name(&self, element_name: &str) -> u32 {
         self.items.iter().fold(0, |sum, x| sum + x.count_element_by_name(element_name))
+            + self.hydrates.iter().fold(0, |sum, x| sum + x.count_element_by_name(element_name))
     }
 
-    fn format(&self) -> String {
-        if self.items.is_empty() && self.charge == -1 {
-            return "e\u{2212}".to_owned();
+    /// Returns the composition: the counts of atoms of every element and the charge.
+    ///
+    /// It is computed from the current items, hydrate parts and charge, which may be changed.
+    pub fn get_composition(&self) -> Composition {
+        let mut composition = Composition::new(vec![], i32::from(self.charge));
+
+        for x in &self.items {
+            x.add_to_composition(&mut composition, 1);
+        }
+
+        for x in &self.hydrates {
+            x.add_to_composition(&mut composition, 1);
+        }
+
+        composition
+    }
+
+    /// Checks whether the entities have the same composition and charge.
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.get_composition() == other.get_composition()
+    }
+
+    /// Formats an entity.
+    pub fn format(&self) -> String {
+        self.format_with_notation(Notation::AsWritten)
+    }
+
+    /// Formats an entity in the notation.
+    pub fn format_with_notation(&self, notation: Notation) -> String {
+        self.format_with_charge_style(notation, ChargeStyle::Braces)
+    }
+
+    /// Formats an entity in the notation with the charge in the style.
+    pub fn format_with_charge_style(
+        &self,
+        notation: Notation,
+        charge_style: ChargeStyle,
+    ) -> String {
+        if self.is_electron() {
+            return charge_style.format_electron();
         }
 
         let mut result = String::new();
 
+        match notation {
+            Notation::AsWritten => {
+                for x in &self.items {
+                    result += &x.format();
+                }
+
+                for x in &self.hydrates {
+                    result += &x.format();
+                }
+            },
+            Notation::Hill => result += &self.get_composition().format_hill(),
+        }
+
+        if self.charge != 0 {
+            result += &charge_style.format_charge(self.charge);
+        }
+
+        if let Some(phase) = self.phase {
+            result += &phase.format();
+        }
+
+        result
+    }
+
+    /// Returns the oxidation states of the elements.
+    pub fn get_oxidation_states(&self) -> OxidationStates {
+        OxidationStates::new(self)
+    }
+
+    /// Formats an entity in LaTeX math mode.
+    pub fn format_latex(&self) -> String {
+        let mut result = "\\mathrm{".to_string();
+
+        if self.is_electron() {
+            result += "e";
+        }
+
         for x in &self.items {
-            result += &x.format();
+            result += &x.format_latex();
         }
 
+        for x in &self.hydrates {
+            result += &x.format_latex();
+        }
+
+        result += "}";
+
         if self.charge != 0 {
             let charge_without_sign = abs(self.charge);
 
+            result += "^{";
+
             if charge_without_sign != 1 {
-                result += &[
-                    "{",
-                    &charge_without_sign.to_string(),
-                    if self.charge > 0 { "+" } else { "\u{2212}" },
-                    "}",
-                ].join("");
+                result += &charge_without_sign.to_string();
             }
+
+            result += if self.charge > 0 { "+}" } else { "-}" };
+        }
+
+        if let Some(phase) = self.phase {
+            result += &["\\mathrm{", &phase.format(), "}"].join("");
         }
 
         result
     }
 }
 
+impl FromStr for Entity {
+    type Err = ParserError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Parser::new(string).parse_formula()
+    }
+}
+
+/// Writes the entity in the syntax of the parser, so that the result is parsed back into the
+/// same entity.
+impl fmt::Display for Entity {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_electron() {
+            formatter.write_str("e")?;
+        } else {
+            for x in &self.items {
+                formatter.write_str(&x.format())?;
+            }
+
+            for x in &self.hydrates {
+                formatter.write_str(&x.format())?;
+            }
+
+            if self.charge != 0 {
+                let charge_without_sign = abs(self.charge);
+
+                formatter.write_str("{")?;
+
+                if charge_without_sign != 1 {
+                    write!(formatter, "{charge_without_sign}")?;
+                }
+
+                formatter.write_str(if self.charge > 0 { "+}" } else { "-}" })?;
+            }
+        }
+
+        if let Some(phase) = self.phase {
+            formatter.write_str(&phase.format())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq for Entity {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+            && self.hydrates == other.hydrates
+            && self.charge == other.charge
+            && self.phase == other.phase
+    }
+}
+
+impl Eq for Entity {}
+
+impl Hash for Entity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.items.hash(state);
+        self.hydrates.hash(state);
+        self.charge.hash(state);
+        self.phase.hash(state);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{entity::Entity, element::Element, item::Item};
+    use num::rational::Ratio;
+    use std::collections::{HashMap, HashSet};
+    use crate::{
+        element::Element,
+        entity::Entity,
+        group::Group,
+        hydrate::Hydrate,
+        item::Item,
+        notation::Notation,
+        phase::Phase,
+    };
+
+    /// Returns the entity of copper(II) sulfate pentahydrate.
+    fn get_copper_sulfate_pentahydrate() -> Entity {
+        let items: Vec<Item> = vec![
+            Element::new("Cu".to_string(), 1).into(),
+            Element::new("S".to_string(), 1).into(),
+            Element::new("O".to_string(), 4).into(),
+        ];
+        let water: Vec<Item> = vec![
+            Element::new("H".to_string(), 2).into(),
+            Element::new("O".to_string(), 1).into(),
+        ];
+
+        Entity::new(items, 0).with_hydrates(vec![Hydrate::new(water, 5)])
+    }
 
     #[test]
     fn test_new() {
         let element = Element::new("H".to_string(), 2);
-        let items: Vec<Box<dyn Item>> = vec![Box::new(element)];
+        let items: Vec<Item> = vec![element.into()];
         let entity = Entity::new(items, 1);
         assert_eq!(entity.charge, 1);
+        assert_eq!(entity.phase, None);
+    }
+
+    #[test]
+    fn test_is_electron() {
+        assert!(Entity::new(vec![], -1).is_electron());
+        assert!(!get_copper_sulfate_pentahydrate().is_electron());
     }
 
     #[test]
     fn test_add_to_elements_names() {
-        // TODO
+        let mut elements_names = HashSet::new();
+        get_copper_sulfate_pentahydrate().add_to_elements_names(&mut elements_names);
+        assert_eq!(elements_names, HashSet::from(["Cu", "S", "O", "H"].map(String::from)));
     }
 
     #[test]
     fn test_count_element_by_name() {
-        // TODO
+        let entity = get_copper_sulfate_pentahydrate();
+        assert_eq!(entity.count_element_by_name("O"), 9);
+        assert_eq!(entity.count_element_by_name("H"), 10);
+        assert_eq!(entity.count_element_by_name("e"), 0);
+        assert_eq!("SO4{2-}".parse::<Entity>().unwrap().count_element_by_name("e"), 0);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let entity = "Fe2(SO4)3".parse::<Entity>().unwrap();
+        assert_eq!(entity.get_composition().count_element_by_name("O"), 12);
+        assert!("Fe2(SO4)3 + H2O".parse::<Entity>().is_err());
+        assert!("".parse::<Entity>().is_err());
+
+        let mut counts = HashMap::new();
+        counts.insert("H2O".parse::<Entity>().unwrap(), 1);
+        assert_eq!(counts.get(&" H2O ".parse::<Entity>().unwrap()), Some(&1));
+    }
+
+    #[test]
+    fn test_fmt() {
+        let formulas = [
+            "Fe2(SO4)3",
+            "H{+}",
+            "Cl{-}",
+            "Cr2O7{2-}",
+            "e",
+            "[Cu(NH3)4]SO4\u{b7}H2O",
+            "CuSO4\u{b7}5H2O(s)",
+            "NH4{+}(aq)",
+        ];
+
+        for x in formulas {
+            let entity = x.parse::<Entity>().unwrap();
+            assert_eq!(entity.to_string(), x);
+            assert_eq!(entity.to_string().parse::<Entity>().unwrap(), entity);
+        }
+
+        assert_eq!("CO3{2\u{2212}}".parse::<Entity>().unwrap().to_string(), "CO3{2-}");
+        assert_eq!("e{-}".parse::<Entity>().unwrap().to_string(), "e");
+        assert_eq!("CuSO4*5H2O".parse::<Entity>().unwrap().to_string(), "CuSO4\u{b7}5H2O");
+    }
+
+    #[test]
+    fn test_get_composition() {
+        let composition = get_copper_sulfate_pentahydrate().get_composition();
+        assert_eq!(
+            composition.elements,
+            [("Cu".to_string(), 1), ("S".to_string(), 1), ("O".to_string(), 9), ("H".to_string(), 10)]
+        );
+        assert_eq!(composition.charge, 0);
+        assert_eq!(Entity::new(vec![], -1).get_composition().charge, -1);
+
+        let mut entity = get_copper_sulfate_pentahydrate();
+        entity.hydrates.clear();
+        entity.charge = 2;
+        assert_eq!(entity.get_composition().count_element_by_name("H"), 0);
+        assert_eq!(entity.get_composition().charge, 2);
+    }
+
+    #[test]
+    fn test_is_equivalent() {
+        let pairs = [
+            ["Fe2(SO4)3", "Fe2S3O12", "true"],
+            ["CH3COOH", "C2H4O2", "true"],
+            ["CuSO4*5H2O", "CuSO9H10", "true"],
+            ["NH4{+}", "NH4", "false"],
+            ["H2O", "H2O2", "false"],
+        ];
+
+        for [x, y, is_equivalent] in pairs {
+            let entity = x.parse::<Entity>().unwrap();
+            assert_eq!(entity.is_equivalent(&y.parse().unwrap()), is_equivalent == "true");
+        }
+    }
+
+    #[test]
+    fn test_format_with_notation() {
+        let entity = "CH3COOH".parse::<Entity>().unwrap();
+        assert_eq!(entity.format_with_notation(Notation::AsWritten), "CH3COOH");
+        assert_eq!(entity.format_with_notation(Notation::Hill), "C2H4O2");
+
+        let entity = "Fe2(SO4)3(aq)".parse::<Entity>().unwrap();
+        assert_eq!(entity.format_with_notation(Notation::Hill), "Fe2O12S3(aq)");
+
+        let entity = "Cr2O7{2-}".parse::<Entity>().unwrap();
+        assert_eq!(entity.format_with_notation(Notation::Hill), "Cr2O7{2\u{2212}}");
+    }
+
+    #[test]
+    fn test_get_oxidation_states() {
+        let entity = "MnO4{-}".parse::<Entity>().unwrap();
+        assert_eq!(entity.get_oxidation_states().get_state("Mn"), Some(Ratio::from_integer(7)));
+    }
+
+    #[test]
+    fn test_format_latex() {
+        let entity = "Fe2(SO4)3".parse::<Entity>().unwrap();
+        assert_eq!(entity.format_latex(), "\\mathrm{Fe_{2}(SO_{4})_{3}}");
+
+        let entity = "CuSO4*5H2O(s)".parse::<Entity>().unwrap();
+        assert_eq!(entity.format_latex(), "\\mathrm{CuSO_{4}\\cdot 5H_{2}O}\\mathrm{(s)}");
+
+        let entity = "Cr2O7{2-}".parse::<Entity>().unwrap();
+        assert_eq!(entity.format_latex(), "\\mathrm{Cr_{2}O_{7}}^{2-}");
+        assert_eq!(Entity::new(vec![], -1).format_latex(), "\\mathrm{e}^{-}");
+        assert_eq!("H{+}".parse::<Entity>().unwrap().format_latex(), "\\mathrm{H}^{+}");
     }
 
     #[test]
     fn test_format() {
-        // TODO
+        assert_eq!(get_copper_sulfate_pentahydrate().format(), "CuSO4\u{b7}5H2O");
+
+        let items: Vec<Item> = vec![
+            Group::new(vec![Element::new("S".to_string(), 1).into()], 1).into(),
+            Element::new("O".to_string(), 4).into(),
+        ];
+        let entity = Entity::new(items, -2).with_phase(Some(Phase::Aqueous));
+        assert_eq!(entity.format(), "(S)O4{2\u{2212}}(aq)");
+        assert_eq!(Entity::new(vec![], -1).format(), "e\u{2212}");
     }
 }
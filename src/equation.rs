@@ -1,6 +1,12 @@
 use std::collections::HashSet;
-use crate::{entity::Entity, item::Item};
-
+use crate::{
+    charge_style::ChargeStyle,
+    composition::Composition,
+    entity::Entity,
+    notation::Notation,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Equation {
     /// Reactants
     pub reactants: Vec<Entity>,
@@ -29,8 +35,56 @@ impl Equation {
         elements_names.into_iter().collect()
     }
 
+    /// Returns the compositions of the reactants followed by the products.
+    pub fn get_compositions(&self) -> Vec<Composition> {
+        self.reactants.iter().chain(&self.products).map(Entity::get_composition).collect()
+    }
+
+    /// Returns the pairs of indices of the reactants and products with the same composition
+    /// in the same phase, sorted by the reactants.
+    ///
+    /// Every reactant and every product is paired at most once, with an identical species in
+    /// preference to an equivalent one, such as an isomer. A species changing its phase is
+    /// not paired.
+    pub fn find_species_on_both_sides(&self) -> Vec<(usize, usize)> {
+        let mut is_reactant_paired = vec![false; self.reactants.len()];
+        let mut is_product_paired = vec![false; self.products.len()];
+        let mut pairs = vec![];
+        for is_identity_required in [true, false] {
+            let is_paired = |x: &Entity, y: &Entity| if is_identity_required {
+                x == y
+            } else {
+                x.phase == y.phase && x.is_equivalent(y)
+            };
+
+            for (i, x) in self.reactants.iter().enumerate() {
+                if is_reactant_paired[i] {
+                    continue;
+                }
+
+                let product_index = self.products.iter().enumerate()
+                    .position(|(j, y)| !is_product_paired[j] && is_paired(x, y));
+
+                if let Some(j) = product_index {
+                    is_reactant_paired[i] = true;
+                    is_product_paired[j] = true;
+                    pairs.push((i, j));
+                }
+            }
+        }
+
+        pairs.sort_unstable();
+
+        pairs
+    }
+
     /// Formats the entities.
-    fn format_entities(coefficients: &[i32], entities: &[Entity]) -> String {
+    fn format_entities(
+        coefficients: &[i32],
+        entities: &[Entity],
+        notation: Notation,
+        charge_style: ChargeStyle,
+    ) -> String {
         let mut result = String::new();
         let mut is_head = true;
 
@@ -48,7 +102,7 @@ impl Equation {
                     result += &[&coefficient.to_string(), "\u{a0}"].join("");
                 }
 
-                result += &entities[i].format();
+                result += &entities[i].format_with_charge_style(notation, charge_style);
             }
         }
 
@@ -57,12 +111,31 @@ impl Equation {
 
     /// Formats an equation.
     pub fn format(&self, coefficients: &[i32]) -> String {
+        self.format_with_notation(coefficients, Notation::AsWritten)
+    }
+
+    /// Formats an equation in the notation.
+    pub fn format_with_notation(&self, coefficients: &[i32], notation: Notation) -> String {
+        self.format_with_charge_style(coefficients, notation, ChargeStyle::Braces)
+    }
+
+    /// Formats an equation in the notation with the charges in the style.
+    ///
+    /// The result, coefficients included, is parsed back into the same equation.
+    pub fn format_with_charge_style(
+        &self,
+        coefficients: &[i32],
+        notation: Notation,
+        charge_style: ChargeStyle,
+    ) -> String {
         [
-            Self::format_entities(coefficients, &self.reactants),
+            Self::format_entities(coefficients, &self.reactants, notation, charge_style),
             " = ".to_string(),
             Self::format_entities(
-                &coefficients[self.reactants.len()..coefficients.len()],
-                &self.products
+                coefficients.get(self.reactants.len()..).unwrap_or_default(),
+                &self.products,
+                notation,
+                charge_style,
             ),
         ].join("")
     }
@@ -70,6 +143,8 @@ impl Equation {
 
 #[cfg(test)]
 mod tests {
+    use crate::{balancer::Balancer, charge_style::ChargeStyle, notation::Notation, parser::Parser};
+
     #[test]
     fn test_new() {
         // TODO
@@ -80,6 +155,34 @@ mod tests {
         // TODO
     }
 
+    #[test]
+    fn test_get_compositions() {
+        let equation = Parser::new("Fe{3+} + e = Fe").parse_equation().unwrap();
+        let compositions = equation.get_compositions();
+        assert_eq!(compositions.len(), 3);
+        assert_eq!(compositions[0].charge, 3);
+        assert_eq!(compositions[1].charge, -1);
+        assert_eq!(compositions[2].count_element_by_name("Fe"), 1);
+    }
+
+    #[test]
+    fn test_find_species_on_both_sides() {
+        let equation = Parser::new("H2O2 + MnO2 = H2O + O2 + MnO2").parse_equation().unwrap();
+        assert_eq!(equation.find_species_on_both_sides(), [(1, 2)]);
+
+        let equation = Parser::new("CH3COOH + H2O = C2H4O2 + H2O + H2O").parse_equation().unwrap();
+        assert_eq!(equation.find_species_on_both_sides(), [(0, 0), (1, 1)]);
+
+        let equation = Parser::new("C2H4O2 + CH3COOH = CH3COOH").parse_equation().unwrap();
+        assert_eq!(equation.find_species_on_both_sides(), [(1, 0)]);
+
+        let equation = Parser::new("H2O(l) = H2O(g)").parse_equation().unwrap();
+        assert!(equation.find_species_on_both_sides().is_empty());
+
+        let equation = Parser::new("H2 + O2 = H2O").parse_equation().unwrap();
+        assert!(equation.find_species_on_both_sides().is_empty());
+    }
+
     #[test]
     fn test_format_entities() {
         // TODO
@@ -87,6 +190,52 @@ mod tests {
 
     #[test]
     fn test_format() {
-        // TODO
+        let equation = Parser::new("H2 + O2 = H2O").parse_equation().unwrap();
+        assert_eq!(equation.format(&[2, 1, 2]), "2\u{a0}H2 + O2 = 2\u{a0}H2O");
+    }
+
+    #[test]
+    fn test_format_with_notation() {
+        let equation = Parser::new("CH3COOH + O2 = CO2 + H2O").parse_equation().unwrap();
+        assert_eq!(
+            equation.format_with_notation(&[1, 2, 2, 2], Notation::Hill),
+            "C2H4O2 + 2\u{a0}O2 = 2\u{a0}CO2 + 2\u{a0}H2O"
+        );
+    }
+
+    #[test]
+    fn test_format_with_charge_style() {
+        let equation = Parser::new("Cr2O7{2-} + H{+} + e = Cr{3+} + H2O").parse_equation().unwrap();
+        assert_eq!(
+            equation.format_with_charge_style(&[], Notation::AsWritten, ChargeStyle::Braces),
+            "Cr2O7{2\u{2212}} + H{+} + e\u{2212} = Cr{3+} + H2O"
+        );
+        assert_eq!(
+            equation.format_with_charge_style(&[], Notation::AsWritten, ChargeStyle::Superscript),
+            "Cr2O7\u{b2}\u{207b} + H\u{207a} + e\u{207b} = Cr\u{b3}\u{207a} + H2O"
+        );
+        assert_eq!(
+            equation.format_with_charge_style(&[], Notation::AsWritten, ChargeStyle::Caret),
+            "Cr2O7^2- + H^+ + e^- = Cr^3+ + H2O"
+        );
+
+        for charge_style in [ChargeStyle::Braces, ChargeStyle::Superscript, ChargeStyle::Caret] {
+            let text = equation.format_with_charge_style(&[], Notation::AsWritten, charge_style);
+            let parsed_equation = Parser::new(&text).parse_equation().unwrap();
+            assert_eq!(parsed_equation.get_compositions(), equation.get_compositions());
+        }
+
+        let balanced_equation = Balancer::new("Cr2O7{2-} + H{+} + e = Cr{3+} + H2O").unwrap()
+            .balance()
+            .unwrap();
+
+        for charge_style in [ChargeStyle::Braces, ChargeStyle::Superscript, ChargeStyle::Caret] {
+            let text = balanced_equation.equation.format_with_charge_style(
+                &balanced_equation.coefficients,
+                Notation::AsWritten,
+                charge_style,
+            );
+            assert_eq!(Parser::new(&text).parse_equation().unwrap(), balanced_equation.equation);
+        }
     }
 }
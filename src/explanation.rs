@@ -0,0 +1,273 @@
+use itertools::Itertools;
+use crate::{balancing_step::BalancingStep, entity::Entity, matrix::Matrix};
+
+/// A step log of the matrix method with the names of the rows and columns of the matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// Names of the elements of the rows, without the charge row, if any species is charged,
+    /// and the empty row
+    pub rows_names: Vec<String>,
+    /// Species of the columns, without the constant column
+    pub species: Vec<Entity>,
+    /// Steps
+    pub steps: Vec<BalancingStep>,
+}
+
+impl Explanation {
+    /// Explanation constructor.
+    pub fn new(rows_names: Vec<String>, species: Vec<Entity>, steps: Vec<BalancingStep>) -> Self {
+        Self { rows_names, species, steps }
+    }
+
+    /// Checks whether the matrix has a row for the charge.
+    fn has_charge_row(&self) -> bool {
+        self.species.iter().any(|x| x.charge != 0)
+    }
+
+    /// Returns the description of the rows after the names of the elements.
+    fn get_other_rows_description(&self) -> &str {
+        if self.has_charge_row() {
+            ", the charge and an empty row for the free variable."
+        } else {
+            " and an empty row for the free variable."
+        }
+    }
+
+    /// Formats a matrix as a text table with the names of the rows and columns.
+    fn format_text_matrix(&self, matrix: &Matrix, is_initial: bool) -> String {
+        let mut header = vec![String::new()];
+        header.extend(self.species.iter().map(|x| x.format()));
+        header.push(String::new());
+
+        let mut table = vec![header];
+
+        for (i, row) in matrix.cells.iter().enumerate() {
+            let mut line = vec![self.get_row_name(i, is_initial)];
+            line.extend(row.iter().map(|x| x.to_string()));
+            table.push(line);
+        }
+
+        let widths: Vec<usize> = (0..table[0].len())
+            .map(|j| table.iter().map(|line| line[j].chars().count()).max().unwrap_or(0))
+            .collect();
+
+        table.iter()
+            .map(|line| line.iter().enumerate()
+                .map(|(j, x)| if j == 0 {
+                    format!("{x:<width$}", width = widths[j])
+                } else {
+                    format!("{x:>width$}", width = widths[j])
+                })
+                .join("  ")
+                .trim_end()
+                .to_string())
+            .join("\n")
+    }
+
+    /// Formats a matrix as a LaTeX array with the names of the rows and columns.
+    fn format_latex_matrix(&self, matrix: &Matrix, is_initial: bool) -> String {
+        let mut lines = vec![format!(
+            "\\begin{{array}}{{l|{}|r}}",
+            "r".repeat(self.species.len()),
+        )];
+
+        let mut header = vec![String::new()];
+        header.extend(self.species.iter().map(|x| x.format_latex()));
+        header.push(String::new());
+        lines.push(header.join(" & ") + " \\\\ \\hline");
+
+        for (i, row) in matrix.cells.iter().enumerate() {
+            let name = self.get_row_name(i, is_initial);
+            let name = if !is_initial {
+                format!("R_{{{}}}", i + 1)
+            } else if i < self.rows_names.len() {
+                format!("\\mathrm{{{name}}}")
+            } else if name.is_empty() {
+                name
+            } else {
+                format!("\\text{{{name}}}")
+            };
+
+            lines.push([name, row.iter().join(" & ")].join(" & ") + " \\\\");
+        }
+
+        lines.push("\\end{array}".to_string());
+
+        lines.join("\n")
+    }
+
+    /// Returns the name of the row by its index: the element, the charge or nothing in the
+    /// initial matrix, and the number of the row after the rows are combined.
+    fn get_row_name(&self, row_index: usize, is_initial: bool) -> String {
+        if !is_initial {
+            return format!("R{}", row_index + 1);
+        }
+
+        match self.rows_names.get(row_index) {
+            Some(x) => x.clone(),
+            None if row_index == self.rows_names.len() && self.has_charge_row() =>
+                "charge".to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// Formats the step log as plain text.
+    pub fn format_text(&self) -> String {
+        let mut paragraphs = vec![
+            [
+                format!(
+                    "Rows: {}{}",
+                    self.rows_names.join(", "),
+                    self.get_other_rows_description(),
+                ),
+                format!(
+                    "Columns: {} and the constant term.",
+                    self.species.iter().map(|x| x.format()).join(", "),
+                ),
+            ].join("\n"),
+        ];
+
+        for (i, step) in self.steps.iter().enumerate() {
+            let description = match step {
+                BalancingStep::InitialMatrix { .. } => "Initial matrix:".to_string(),
+                BalancingStep::Elimination { operation, .. } => operation.get_description(),
+                BalancingStep::FreeVariable { column_index, .. } => format!(
+                    "Set the coefficient of {} to 1 in the empty row and eliminate again.",
+                    self.species[*column_index].format(),
+                ),
+                BalancingStep::Scaling { least_common_multiple, coefficients } => format!(
+                    "Multiply by the least common multiple of the diagonal cells, {}: {}.",
+                    least_common_multiple,
+                    coefficients.iter().join(", "),
+                ),
+            };
+
+            let mut paragraph = format!("{}. {}", i + 1, description);
+
+            if let Some(matrix) = step.get_matrix() {
+                let is_initial = matches!(step, BalancingStep::InitialMatrix { .. });
+                paragraph += "\n";
+                paragraph += &self.format_text_matrix(matrix, is_initial);
+            }
+
+            paragraphs.push(paragraph);
+        }
+
+        paragraphs.join("\n\n")
+    }
+
+    /// Formats the step log as a LaTeX fragment with a table for every matrix.
+    pub fn format_latex(&self) -> String {
+        let mut paragraphs = vec![
+            [
+                format!(
+                    "Rows: ${}${}",
+                    self.rows_names.iter().map(|x| format!("\\mathrm{{{x}}}")).join(", "),
+                    self.get_other_rows_description(),
+                ),
+                format!(
+                    "Columns: ${}$ and the constant term.",
+                    self.species.iter().map(|x| x.format_latex()).join(", "),
+                ),
+            ].join("\n"),
+        ];
+
+        for (i, step) in self.steps.iter().enumerate() {
+            let description = match step {
+                BalancingStep::InitialMatrix { .. } => "Initial matrix:".to_string(),
+                BalancingStep::Elimination { operation, .. } => operation.get_description()
+                    .replace('\u{b7}', "$\\cdot$")
+                    .replace('\u{2212}', "$-$"),
+                BalancingStep::FreeVariable { column_index, .. } => format!(
+                    "Set the coefficient of ${}$ to 1 in the empty row and eliminate again.",
+                    self.species[*column_index].format_latex(),
+                ),
+                BalancingStep::Scaling { least_common_multiple, coefficients } => format!(
+                    "Multiply by the least common multiple of the diagonal cells, {}: ${}$.",
+                    least_common_multiple,
+                    coefficients.iter().join(", "),
+                ),
+            };
+
+            let mut paragraph = format!("{}. {}", i + 1, description);
+
+            if let Some(matrix) = step.get_matrix() {
+                let is_initial = matches!(step, BalancingStep::InitialMatrix { .. });
+                paragraph += "\n\\[\n";
+                paragraph += &self.format_latex_matrix(matrix, is_initial);
+                paragraph += "\n\\]";
+            }
+
+            paragraphs.push(paragraph);
+        }
+
+        paragraphs.join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        balancing_step::BalancingStep,
+        entity::Entity,
+        explanation::Explanation,
+        matrix::Matrix,
+    };
+
+    fn get_explanation() -> Explanation {
+        let mut matrix = Matrix::new(2, 3);
+        matrix.cells = vec![vec![2, -2, 0], vec![0, 0, 0]];
+
+        Explanation::new(
+            vec!["H".to_string()],
+            vec!["H2".parse::<Entity>().unwrap(), "H".parse::<Entity>().unwrap()],
+            vec![
+                BalancingStep::InitialMatrix { matrix },
+                BalancingStep::Scaling { least_common_multiple: 1, coefficients: vec![1, 2] },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_format_text() {
+        assert_eq!(
+            get_explanation().format_text(),
+            "Rows: H and an empty row for the free variable.\n\
+                Columns: H2, H and the constant term.\n\n\
+                1. Initial matrix:\n\
+                \x20  H2   H\n\
+                H   2  -2  0\n\
+                \x20   0   0  0\n\n\
+                2. Multiply by the least common multiple of the diagonal cells, 1: 1, 2."
+        );
+
+        let explanation = Explanation::new(
+            vec!["Fe".to_string()],
+            ["Fe{3+}", "e", "Fe{2+}"].map(|x| x.parse::<Entity>().unwrap()).to_vec(),
+            vec![],
+        );
+        assert_eq!(
+            explanation.format_text(),
+            "Rows: Fe, the charge and an empty row for the free variable.\n\
+                Columns: Fe{3+}, e\u{2212}, Fe{2+} and the constant term."
+        );
+    }
+
+    #[test]
+    fn test_format_latex() {
+        assert_eq!(
+            get_explanation().format_latex(),
+            "Rows: $\\mathrm{H}$ and an empty row for the free variable.\n\
+                Columns: $\\mathrm{H_{2}}, \\mathrm{H}$ and the constant term.\n\n\
+                1. Initial matrix:\n\
+                \\[\n\
+                \\begin{array}{l|rr|r}\n\
+                \x20& \\mathrm{H_{2}} & \\mathrm{H} &  \\\\ \\hline\n\
+                \\mathrm{H} & 2 & -2 & 0 \\\\\n\
+                \x20& 0 & 0 & 0 \\\\\n\
+                \\end{array}\n\
+                \\]\n\n\
+                2. Multiply by the least common multiple of the diagonal cells, 1: $1, 2$."
+        );
+    }
+}
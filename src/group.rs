@@ -1,40 +1,65 @@
-use std::collections::{HashSet, hash_map::RandomState};
-use crate::item::Item;
+use std::{collections::{HashSet, hash_map::RandomState}, hash::{Hash, Hasher}};
+use crate::{bracket::Bracket, composition::Composition, item::Item, span::Span};
 
+#[derive(Debug, Clone)]
 pub struct Group {
     /// Items
-    pub items: Vec<Box<dyn Item>>,
+    pub items: Vec<Item>,
     /// Count
     pub count: u8,
+    /// Kind of brackets
+    pub bracket: Bracket,
+    /// Span in the input
+    pub span: Span,
 }
 
 impl Group {
     /// Group constructor.
-    pub fn new(items: Vec<Box<dyn Item>>, count: u8) -> Self {
-        Self { items, count }
+    pub fn new(items: Vec<Item>, count: u8) -> Self {
+        Self { items, count, bracket: Bracket::Round, span: Span::default() }
+    }
+
+    /// Sets the kind of brackets.
+    pub fn with_bracket(mut self, bracket: Bracket) -> Self {
+        self.bracket = bracket;
+        self
     }
-}
 
-impl Item for Group {
-    fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>) {
+    /// Sets the span in the input.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Adds the element name to set of element names.
+    pub fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>) {
         for x in &self.items {
             x.add_to_elements_names(elements_names);
         }
     }
 
-    fn count_element_by_name(&self, element_name: &str) -> u32 {
+    /// Counts the number of atoms of element by its name.
+    pub fn count_element_by_name(&self, element_name: &str) -> u32 {
         u32::from(self.count)
             * self.items.iter().fold(0, |sum, x| sum + x.count_element_by_name(element_name))
     }
 
-    fn format(&self) -> String {
-        let mut result = "(".to_string();
+    /// Adds the atoms of items multiplied by the factor to the composition.
+    pub fn add_to_composition(&self, composition: &mut Composition, factor: i32) {
+        for x in &self.items {
+            x.add_to_composition(composition, i32::from(self.count) * factor);
+        }
+    }
+
+    /// Formats a group.
+    pub fn format(&self) -> String {
+        let mut result = self.bracket.get_opening().to_string();
 
         for x in &self.items {
             result += &x.format();
         }
 
-        result += ")";
+        result += self.bracket.get_closing();
 
         if self.count != 1 {
             result += &self.count.to_string();
@@ -42,19 +67,67 @@ impl Item for Group {
 
         result
     }
+
+    /// Formats a group in LaTeX.
+    pub fn format_latex(&self) -> String {
+        let mut result = self.bracket.get_opening().to_string();
+
+        for x in &self.items {
+            result += &x.format_latex();
+        }
+
+        result += self.bracket.get_closing();
+
+        if self.count != 1 {
+            result += &format!("_{{{}}}", self.count);
+        }
+
+        result
+    }
+}
+
+impl PartialEq for Group {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.count == other.count && self.bracket == other.bracket
+    }
+}
+
+impl Eq for Group {}
+
+impl Hash for Group {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.items.hash(state);
+        self.count.hash(state);
+        self.bracket.hash(state);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use crate::{element::Element, group::Group, item::Item};
+    use crate::{
+        bracket::Bracket,
+        composition::Composition,
+        element::Element,
+        group::Group,
+        item::Item,
+    };
 
     #[test]
     fn test_new() {
         let element = Element::new("H".to_string(), 2);
-        let items: Vec<Box<dyn Item>> = vec![Box::new(element)];
+        let items: Vec<Item> = vec![element.into()];
         let group = Group::new(items, 1);
         assert_eq!(group.count, 1);
+        assert_eq!(group.bracket, Bracket::Round);
+    }
+
+    #[test]
+    fn test_with_bracket() {
+        let element = Element::new("H".to_string(), 2);
+        let group = Group::new(vec![element.into()], 1).with_bracket(Bracket::Square);
+        assert_eq!(group.bracket, Bracket::Square);
+        assert_ne!(group, Group::new(group.items.clone(), 1));
     }
 
     #[test]
@@ -62,42 +135,75 @@ mod tests {
         let mut elements_names = HashSet::new();
         let mut result = HashSet::new();
         let element = Element::new("H".to_string(), 2);
-        let items: Vec<Box<dyn Item>> = vec![Box::new(element)];
+        let items: Vec<Item> = vec![element.into()];
         let group = Group::new(items, 1);
         group.add_to_elements_names(&mut elements_names);
         result.insert("H".to_string());
         assert_eq!(elements_names, result);
 
-        // TODO
+        let items: Vec<Item> = vec![
+            Element::new("O".to_string(), 1).into(),
+            Group::new(vec![Element::new("N".to_string(), 1).into()], 2).into(),
+        ];
+        let group = Group::new(items, 1);
+        group.add_to_elements_names(&mut elements_names);
+        result.insert("O".to_string());
+        result.insert("N".to_string());
+        assert_eq!(elements_names, result);
     }
 
     #[test]
     fn test_count_element_by_name() {
         let element = Element::new("H".to_string(), 2);
-        let items: Vec<Box<dyn Item>> = vec![Box::new(element)];
+        let items: Vec<Item> = vec![element.into()];
         let group = Group::new(items, 1);
         assert_eq!(group.count_element_by_name("H"), 2);
 
         let element = Element::new("H".to_string(), 2);
-        let items: Vec<Box<dyn Item>> = vec![Box::new(element)];
+        let items: Vec<Item> = vec![element.into()];
         let group = Group::new(items, 2);
         assert_eq!(group.count_element_by_name("H"), 4);
 
-        // TODO
+        let items: Vec<Item> = vec![
+            Element::new("H".to_string(), 1).into(),
+            Group::new(vec![Element::new("H".to_string(), 2).into()], 3).into(),
+        ];
+        let group = Group::new(items, 2);
+        assert_eq!(group.count_element_by_name("H"), 14);
+        assert_eq!(group.count_element_by_name("O"), 0);
+    }
+
+    #[test]
+    fn test_add_to_composition() {
+        let items: Vec<Item> = vec![
+            Element::new("O".to_string(), 1).into(),
+            Element::new("H".to_string(), 1).into(),
+        ];
+        let mut composition = Composition::default();
+        Group::new(items, 2).add_to_composition(&mut composition, 1);
+        assert_eq!(composition.elements, [("O".to_string(), 2), ("H".to_string(), 2)]);
     }
 
     #[test]
     fn test_format() {
         let element = Element::new("H".to_string(), 2);
-        let items: Vec<Box<dyn Item>> = vec![Box::new(element)];
+        let items: Vec<Item> = vec![element.into()];
         let group = Group::new(items, 1);
         assert_eq!(group.format(), "(H2)");
 
         let element = Element::new("H".to_string(), 2);
-        let items: Vec<Box<dyn Item>> = vec![Box::new(element)];
+        let items: Vec<Item> = vec![element.into()];
         let group = Group::new(items, 2);
         assert_eq!(group.format(), "(H2)2");
 
-        // TODO
+        let element = Element::new("H".to_string(), 2);
+        let group = Group::new(vec![element.into()], 4).with_bracket(Bracket::Square);
+        assert_eq!(group.format(), "[H2]4");
+    }
+
+    #[test]
+    fn test_format_latex() {
+        let element = Element::new("H".to_string(), 2);
+        assert_eq!(Group::new(vec![element.into()], 3).format_latex(), "(H_{2})_{3}");
     }
 }
@@ -0,0 +1,420 @@
+use itertools::Itertools;
+use num::integer::{gcd, lcm};
+use crate::{
+    balanced_equation::BalancedEquation,
+    balancer::Balancer,
+    balancer_error::BalancerError,
+    composition::Composition,
+    entity::Entity,
+    equation::Equation,
+    half_reaction_stage::HalfReactionStage,
+    half_reaction_step::HalfReactionStep,
+    medium::Medium,
+    sides::Sides,
+};
+
+/// A worked solution of the half-reaction (ion–electron) method: the skeleton equation is
+/// split into the oxidation and the reduction, each is balanced with water, the ions of
+/// the medium and electrons, and the two are combined.
+#[derive(Debug, Clone)]
+pub struct HalfReactionSolution {
+    /// Medium
+    pub medium: Medium,
+    /// Steps with the intermediate half-equations
+    pub steps: Vec<HalfReactionStep>,
+    /// Balanced net equation
+    pub balanced_equation: BalancedEquation,
+}
+
+impl HalfReactionSolution {
+    /// Balances the skeleton equation in the medium by the half-reaction method.
+    pub fn new(equation: &Equation, medium: Medium) -> Result<Self, BalancerError> {
+        let (first, second) = Self::split(equation)?;
+        let first_stages = Self::balance_half_reaction(&first, medium)?;
+        let second_stages = Self::balance_half_reaction(&second, medium)?;
+        let first_electrons_count =
+            Self::count_electrons(&first_stages[first_stages.len() - 1].1);
+        let second_electrons_count =
+            Self::count_electrons(&second_stages[second_stages.len() - 1].1);
+
+        let (oxidation, reduction, oxidation_stages, reduction_stages) =
+            match (first_electrons_count, second_electrons_count) {
+                (x, y) if x > 0 && y < 0 => (first, second, first_stages, second_stages),
+                (x, y) if x < 0 && y > 0 => (second, first, second_stages, first_stages),
+                _ => return Err(BalancerError::ElectronsAreNotTransferred),
+            };
+
+        let oxidation_sides = oxidation_stages[oxidation_stages.len() - 1].1.clone();
+        let reduction_sides = reduction_stages[reduction_stages.len() - 1].1.clone();
+        let electrons_lost = Self::count_electrons(&oxidation_sides);
+        let electrons_gained = -Self::count_electrons(&reduction_sides);
+        let electrons_count = lcm(electrons_lost, electrons_gained);
+        let oxidation_factor = electrons_count / electrons_lost;
+        let reduction_factor = electrons_count / electrons_gained;
+
+        let mut steps = vec![HalfReactionStep::Split { oxidation, reduction }];
+
+        for (is_oxidation, stages) in [(true, oxidation_stages), (false, reduction_stages)] {
+            for (stage, sides) in stages {
+                steps.push(HalfReactionStep::BalanceHalfReaction {
+                    is_oxidation,
+                    stage,
+                    half_reaction: sides.get_balanced_equation(),
+                });
+            }
+        }
+
+        steps.push(HalfReactionStep::EqualizeElectrons {
+            oxidation_factor,
+            reduction_factor,
+            electrons_count,
+        });
+
+        let mut sides = oxidation_sides.scale(oxidation_factor);
+
+        sides.add(&reduction_sides.scale(reduction_factor));
+
+        steps.push(HalfReactionStep::Combine { equation: sides.get_balanced_equation() });
+
+        sides.cancel();
+        sides.reduce();
+
+        let get_position = |entities: &[Entity], entity: &Entity| entities.iter()
+            .position(|x| x == entity)
+            .unwrap_or(usize::MAX);
+
+        sides.reactants.sort_by_key(|(x, _)| get_position(&equation.reactants, x));
+        sides.products.sort_by_key(|(x, _)| get_position(&equation.products, x));
+
+        let balanced_equation = sides.get_balanced_equation();
+
+        steps.push(HalfReactionStep::Cancel { equation: balanced_equation.clone() });
+
+        Ok(Self { medium, steps, balanced_equation })
+    }
+
+    /// Returns water, the hydrogen ion, the hydroxide ion and the electron.
+    fn get_medium_species() -> [Entity; 4] {
+        ["H2O", "H{+}", "OH{-}", "e"]
+            .map(|x| x.parse::<Entity>().expect("Medium species are valid formulas."))
+    }
+
+    /// Returns the names of the elements by which the species are paired into half-reactions:
+    /// the elements other than hydrogen and oxygen, or else oxygen, or else hydrogen.
+    fn get_key_elements_names(entity: &Entity) -> Vec<String> {
+        let composition = entity.get_composition();
+        let elements_names: Vec<String> = composition.get_elements_names().into_iter()
+            .filter(|&x| x != "H" && x != "O")
+            .map(|x| x.to_string())
+            .collect();
+
+        if !elements_names.is_empty() {
+            elements_names
+        } else if composition.count_element_by_name("O") != 0 {
+            vec!["O".to_string()]
+        } else if composition.count_element_by_name("H") != 0 {
+            vec!["H".to_string()]
+        } else {
+            vec![]
+        }
+    }
+
+    /// Splits the skeleton equation, without water, the ions of the medium and electrons,
+    /// into two half-reactions by the elements the species share.
+    fn split(equation: &Equation) -> Result<(Equation, Equation), BalancerError> {
+        let medium_species = Self::get_medium_species();
+        let is_skeleton_species = |x: &&Entity| !medium_species.contains(x);
+        let reactants: Vec<Entity> = equation.reactants.iter()
+            .filter(is_skeleton_species)
+            .cloned()
+            .collect();
+        let products: Vec<Entity> = equation.products.iter()
+            .filter(is_skeleton_species)
+            .cloned()
+            .collect();
+        let entities: Vec<&Entity> = reactants.iter().chain(&products).collect();
+        let key_elements_names: Vec<Vec<String>> = entities.iter()
+            .map(|x| Self::get_key_elements_names(x))
+            .collect();
+        let mut labels: Vec<usize> = (0..entities.len()).collect();
+
+        for i in 0..entities.len() {
+            for j in (i + 1)..entities.len() {
+                let is_connected = key_elements_names[i].iter()
+                    .any(|x| key_elements_names[j].contains(x));
+
+                if is_connected && labels[i] != labels[j] {
+                    let (old_label, new_label) = (labels[j], labels[i]);
+
+                    for x in labels.iter_mut().filter(|x| **x == old_label) {
+                        *x = new_label;
+                    }
+                }
+            }
+        }
+
+        let mut groups: Vec<(Vec<Entity>, Vec<Entity>)> = vec![];
+        let mut groups_labels: Vec<usize> = vec![];
+
+        for (i, entity) in entities.iter().enumerate() {
+            let group_index = match groups_labels.iter().position(|&x| x == labels[i]) {
+                Some(x) => x,
+                None => {
+                    groups_labels.push(labels[i]);
+                    groups.push((vec![], vec![]));
+                    groups.len() - 1
+                },
+            };
+
+            if i < reactants.len() {
+                groups[group_index].0.push((*entity).clone());
+            } else {
+                groups[group_index].1.push((*entity).clone());
+            }
+        }
+
+        match groups.as_slice() {
+            [(a, b), (c, d)] if !a.is_empty() && !b.is_empty() && !c.is_empty() && !d.is_empty() =>
+                Ok((Equation::new(a.clone(), b.clone()), Equation::new(c.clone(), d.clone()))),
+            [(a, b)] if a.len() == 1 && b.len() == 2 => Ok((
+                Equation::new(a.clone(), vec![b[0].clone()]),
+                Equation::new(a.clone(), vec![b[1].clone()]),
+            )),
+            [(a, b)] if a.len() == 2 && b.len() == 1 => Ok((
+                Equation::new(vec![a[0].clone()], b.clone()),
+                Equation::new(vec![a[1].clone()], b.clone()),
+            )),
+            _ => Err(BalancerError::HalfReactionsAreNotFound),
+        }
+    }
+
+    /// Balances a half-reaction, returning the half-equation after every stage.
+    fn balance_half_reaction(
+        half_reaction: &Equation,
+        medium: Medium,
+    ) -> Result<Vec<(HalfReactionStage, Sides)>, BalancerError> {
+        let [water, hydrogen_ion, hydroxide_ion, electron] = Self::get_medium_species();
+
+        let Some(mut sides) = Self::balance_atoms(half_reaction) else {
+            let medium_ion = if medium == Medium::Basic { hydroxide_ion } else { hydrogen_ion };
+            let mut reactants = half_reaction.reactants.clone();
+            reactants.extend([water, medium_ion, electron]);
+
+            let balanced_equation = Balancer::from_equation(
+                Equation::new(reactants, half_reaction.products.clone()),
+            ).with_moving(true).balance()?;
+
+            return Ok(vec![(
+                HalfReactionStage::Matrix,
+                Sides::from_balanced_equation(&balanced_equation),
+            )]);
+        };
+        let mut stages = vec![(HalfReactionStage::Atoms, sides.clone())];
+
+        if Self::balance_element(&mut sides, "O", &water) {
+            stages.push((HalfReactionStage::Oxygen, sides.clone()));
+        }
+
+        if Self::balance_element(&mut sides, "H", &hydrogen_ion) {
+            stages.push((HalfReactionStage::Hydrogen, sides.clone()));
+        }
+
+        let charge_difference = Self::get_difference(&sides).charge;
+
+        if charge_difference > 0 {
+            Sides::add_species(&mut sides.reactants, &electron, charge_difference);
+        } else if charge_difference < 0 {
+            Sides::add_species(&mut sides.products, &electron, -charge_difference);
+        }
+
+        stages.push((HalfReactionStage::Charge, sides.clone()));
+
+        if medium == Medium::Basic {
+            let hydrogen_ions = (
+                Self::count_species(&sides.reactants, &hydrogen_ion),
+                Self::count_species(&sides.products, &hydrogen_ion),
+            );
+
+            let (side, other_side, count) = match hydrogen_ions {
+                (x, _) if x > 0 => (&mut sides.reactants, &mut sides.products, x),
+                (_, x) if x > 0 => (&mut sides.products, &mut sides.reactants, x),
+                _ => return Ok(stages),
+            };
+
+            side.retain(|(x, _)| *x != hydrogen_ion);
+            Sides::add_species(side, &water, count);
+            Sides::add_species(other_side, &hydroxide_ion, count);
+            sides.cancel();
+            stages.push((HalfReactionStage::Neutralization, sides.clone()));
+        }
+
+        Ok(stages)
+    }
+
+    /// Balances the element by adding the species to the side lacking it, returning whether
+    /// the species is added.
+    fn balance_element(sides: &mut Sides, element_name: &str, entity: &Entity) -> bool {
+        let difference = Self::get_difference(sides).count_element_by_name(element_name);
+
+        if difference > 0 {
+            Sides::add_species(&mut sides.products, entity, difference);
+        } else if difference < 0 {
+            Sides::add_species(&mut sides.reactants, entity, -difference);
+        }
+
+        difference != 0
+    }
+
+    /// Balances the atoms other than hydrogen and oxygen of a half-reaction with one reactant
+    /// and one product by the first element they share, returning `None` if it fails.
+    fn balance_atoms(half_reaction: &Equation) -> Option<Sides> {
+        let ([reactant], [product]) =
+            (half_reaction.reactants.as_slice(), half_reaction.products.as_slice()) else {
+            return None;
+        };
+        let reactant_composition = reactant.get_composition();
+        let product_composition = product.get_composition();
+        let element_name = Self::get_key_elements_names(reactant).into_iter()
+            .find(|x| product_composition.count_element_by_name(x) != 0)?;
+        let reactant_count = reactant_composition.count_element_by_name(&element_name);
+        let product_count = product_composition.count_element_by_name(&element_name);
+        let divisor = gcd(reactant_count, product_count);
+        let sides = Sides::new(
+            vec![(reactant.clone(), product_count / divisor)],
+            vec![(product.clone(), reactant_count / divisor)],
+        );
+        let is_balanced = Self::get_difference(&sides).elements.iter()
+            .all(|(x, _)| x == "H" || x == "O");
+
+        if is_balanced { Some(sides) } else { None }
+    }
+
+    /// Returns the composition of the reactants minus the products.
+    fn get_difference(sides: &Sides) -> Composition {
+        let get_composition = |side: &[(Entity, i32)]| side.iter()
+            .fold(Composition::default(), |result, (x, count)| {
+                result.add(&x.get_composition().scale(*count))
+            });
+
+        get_composition(&sides.reactants).subtract(&get_composition(&sides.products))
+    }
+
+    /// Returns the count of the species on a side.
+    fn count_species(side: &[(Entity, i32)], entity: &Entity) -> i32 {
+        side.iter().filter(|(x, _)| x == entity).map(|(_, x)| x).sum()
+    }
+
+    /// Returns the electrons on the products side minus the electrons on the reactants side.
+    fn count_electrons(sides: &Sides) -> i32 {
+        let electron = "e".parse::<Entity>().expect("The electron is a valid formula.");
+
+        Self::count_species(&sides.products, &electron)
+            - Self::count_species(&sides.reactants, &electron)
+    }
+
+    /// Formats the steps, one per line.
+    pub fn format(&self) -> String {
+        self.steps.iter()
+            .enumerate()
+            .map(|(i, x)| format!("{}. {}", i + 1, x.get_description()))
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        balancer_error::BalancerError,
+        half_reaction_solution::HalfReactionSolution,
+        half_reaction_stage::HalfReactionStage,
+        half_reaction_step::HalfReactionStep,
+        medium::Medium,
+        parser::Parser,
+    };
+
+    fn get_solution(equation: &str, medium: Medium) -> Result<HalfReactionSolution, BalancerError> {
+        HalfReactionSolution::new(&Parser::new(equation).parse_equation().unwrap(), medium)
+    }
+
+    fn get_result(equation: &str, medium: Medium) -> String {
+        get_solution(equation, medium).unwrap().balanced_equation.format()
+    }
+
+    #[test]
+    fn test_new() {
+        let solution = get_solution("MnO4{-} + Fe{2+} = Mn{2+} + Fe{3+}", Medium::Acidic).unwrap();
+        assert_eq!(solution.balanced_equation.coefficients, [1, 5, 8, 1, 5, 4]);
+        assert_eq!(solution.steps.len(), 10);
+        assert!(matches!(
+            solution.steps[7],
+            HalfReactionStep::EqualizeElectrons {
+                oxidation_factor: 5,
+                reduction_factor: 1,
+                electrons_count: 5,
+            }
+        ));
+    }
+
+    #[test]
+    fn test_new_with_basic_medium() {
+        let solution = get_solution("MnO4{-} + I{-} = MnO2 + I2", Medium::Basic).unwrap();
+        assert!(solution.steps.iter().any(|x| matches!(
+            x,
+            HalfReactionStep::BalanceHalfReaction {
+                is_oxidation: false,
+                stage: HalfReactionStage::Neutralization,
+                ..
+            }
+        )));
+        assert_eq!(
+            get_result("MnO4{-} + I{-} = MnO2 + I2", Medium::Basic),
+            "2\u{a0}MnO4{\u{2212}} + 6\u{a0}I{\u{2212}} + 4\u{a0}H2O = 2\u{a0}MnO2 + 3\u{a0}I2 \
+                + 8\u{a0}OH{\u{2212}}"
+        );
+    }
+
+    #[test]
+    fn test_new_with_proportionation() {
+        assert_eq!(
+            get_result("Cl2 = Cl{-} + ClO3{-}", Medium::Basic),
+            "3\u{a0}Cl2 + 6\u{a0}OH{\u{2212}} = 5\u{a0}Cl{\u{2212}} + ClO3{\u{2212}} + 3\u{a0}H2O"
+        );
+        assert_eq!(
+            get_result("H2S + SO2 = S", Medium::Acidic),
+            "2\u{a0}H2S + SO2 = 3\u{a0}S + 2\u{a0}H2O"
+        );
+    }
+
+    #[test]
+    fn test_new_with_matrix() {
+        let solution =
+            get_solution("As2S3 + NO3{-} = H3AsO4 + SO4{2-} + NO", Medium::Acidic).unwrap();
+        assert!(matches!(
+            solution.steps[1],
+            HalfReactionStep::BalanceHalfReaction { stage: HalfReactionStage::Matrix, .. }
+        ));
+        assert_eq!(solution.balanced_equation.coefficients, [3, 28, 4, 10, 6, 9, 28]);
+    }
+
+    #[test]
+    fn test_new_with_errors() {
+        assert!(matches!(
+            get_solution("H2 + O2 = H2O", Medium::Acidic),
+            Err(BalancerError::HalfReactionsAreNotFound)
+        ));
+        assert!(matches!(
+            get_solution("Fe{2+} + Sn{2+} = Fe{3+} + Sn{4+}", Medium::Acidic),
+            Err(BalancerError::ElectronsAreNotTransferred)
+        ));
+    }
+
+    #[test]
+    fn test_format() {
+        let solution = get_solution("H2S + SO2 = S", Medium::Acidic).unwrap();
+        assert_eq!(solution.format().lines().count(), 11);
+        assert_eq!(
+            solution.format().lines().next(),
+            Some("1. Split into the half-reactions: H2S = S (oxidation) and SO2 = S (reduction).")
+        );
+    }
+}
@@ -0,0 +1,31 @@
+/// A stage of balancing a half-reaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HalfReactionStage {
+    /// The atoms other than hydrogen and oxygen are balanced.
+    Atoms,
+    /// Oxygen is balanced with water.
+    Oxygen,
+    /// Hydrogen is balanced with hydrogen ions.
+    Hydrogen,
+    /// The charge is balanced with electrons.
+    Charge,
+    /// Hydroxide ions are added to both sides to neutralize the hydrogen ions.
+    Neutralization,
+    /// The half-reaction is balanced by the matrix method with water, the ions of the medium
+    /// and electrons.
+    Matrix,
+}
+
+impl HalfReactionStage {
+    /// Returns the description.
+    pub fn get_description(&self) -> &str {
+        match self {
+            Self::Atoms => "balance the atoms other than H and O",
+            Self::Oxygen => "balance O with H2O",
+            Self::Hydrogen => "balance H with H+",
+            Self::Charge => "balance the charge with electrons",
+            Self::Neutralization => "add OH\u{2212} to both sides to neutralize H+",
+            Self::Matrix => "balance with H2O, the ions of the medium and electrons",
+        }
+    }
+}
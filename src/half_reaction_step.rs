@@ -0,0 +1,85 @@
+use crate::{
+    balanced_equation::BalancedEquation,
+    equation::Equation,
+    half_reaction_stage::HalfReactionStage,
+};
+
+/// A step of the half-reaction method.
+#[derive(Debug, Clone)]
+pub enum HalfReactionStep {
+    /// The skeleton equation is split into the half-reactions.
+    Split { oxidation: Equation, reduction: Equation },
+    /// A stage of balancing the half-reaction.
+    BalanceHalfReaction {
+        is_oxidation: bool,
+        stage: HalfReactionStage,
+        half_reaction: BalancedEquation,
+    },
+    /// The half-reactions are multiplied to transfer the same number of electrons.
+    EqualizeElectrons { oxidation_factor: i32, reduction_factor: i32, electrons_count: i32 },
+    /// The multiplied half-reactions are added.
+    Combine { equation: BalancedEquation },
+    /// The species on both sides are cancelled.
+    Cancel { equation: BalancedEquation },
+}
+
+impl HalfReactionStep {
+    /// Returns the name of the half-reaction.
+    fn get_half_reaction_name(is_oxidation: bool) -> &'static str {
+        if is_oxidation { "Oxidation" } else { "Reduction" }
+    }
+
+    /// Returns the description with the half-equation or equation after the step.
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::Split { oxidation, reduction } => format!(
+                "Split into the half-reactions: {} (oxidation) and {} (reduction).",
+                oxidation.format(&[]),
+                reduction.format(&[]),
+            ),
+            Self::BalanceHalfReaction { is_oxidation, stage, half_reaction } => format!(
+                "{}, {}: {}",
+                Self::get_half_reaction_name(*is_oxidation),
+                stage.get_description(),
+                half_reaction.format(),
+            ),
+            Self::EqualizeElectrons { oxidation_factor, reduction_factor, electrons_count } =>
+                format!(
+                    "Multiply the oxidation by {oxidation_factor} and the reduction by \
+                        {reduction_factor} to transfer {electrons_count} electrons.",
+                ),
+            Self::Combine { equation } =>
+                format!("Add the half-reactions: {}", equation.format()),
+            Self::Cancel { equation } =>
+                format!("Cancel the species on both sides: {}", equation.format()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{half_reaction_step::HalfReactionStep, parser::Parser};
+
+    #[test]
+    fn test_get_description() {
+        let step = HalfReactionStep::Split {
+            oxidation: Parser::new("Fe{2+} = Fe{3+}").parse_equation().unwrap(),
+            reduction: Parser::new("MnO4{-} = Mn{2+}").parse_equation().unwrap(),
+        };
+        assert_eq!(
+            step.get_description(),
+            "Split into the half-reactions: Fe{2+} = Fe{3+} (oxidation) and MnO4{−} = Mn{2+} \
+                (reduction)."
+        );
+
+        let step = HalfReactionStep::EqualizeElectrons {
+            oxidation_factor: 5,
+            reduction_factor: 1,
+            electrons_count: 5,
+        };
+        assert_eq!(
+            step.get_description(),
+            "Multiply the oxidation by 5 and the reduction by 1 to transfer 5 electrons."
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use itertools::Itertools;
+use num::rational::Ratio;
+use crate::{
+    balanced_equation::BalancedEquation,
+    balancer_error::BalancerError,
+    dissociation::Dissociation,
+    entity::Entity,
+    equation::Equation,
+    half_reaction_solution::HalfReactionSolution,
+    half_reaction_step::HalfReactionStep,
+    medium::Medium,
+    redox_couple::RedoxCouple,
+};
+
+/// The redox couples of a full equation with their balanced half-reactions.
+#[derive(Debug, Clone)]
+pub struct HalfReactions {
+    /// Medium
+    pub medium: Medium,
+    /// Skeleton ionic equation of the species taking part in the electron transfer
+    pub ionic_equation: Equation,
+    /// Couple of the oxidation
+    pub oxidation_couple: RedoxCouple,
+    /// Couple of the reduction
+    pub reduction_couple: RedoxCouple,
+    /// Balanced oxidation half-reaction with the electrons
+    pub oxidation: BalancedEquation,
+    /// Balanced reduction half-reaction with the electrons
+    pub reduction: BalancedEquation,
+}
+
+impl HalfReactions {
+    /// Identifies the redox couples of the molecular or ionic equation, balanced or not, and
+    /// balances their half-reactions in the medium.
+    pub fn new(equation: &Equation, medium: Medium) -> Result<Self, BalancerError> {
+        let ionic_equation = Self::get_ionic_equation(equation);
+
+        if ionic_equation.reactants.is_empty() || ionic_equation.products.is_empty() {
+            return Err(BalancerError::ElectronsAreNotTransferred);
+        }
+
+        let solution = HalfReactionSolution::new(&ionic_equation, medium)?;
+        let mut couples = None;
+        let mut half_reactions = [None, None];
+
+        for step in solution.steps {
+            match step {
+                HalfReactionStep::Split { oxidation, reduction } => couples = Some((
+                    RedoxCouple::new(oxidation.products, oxidation.reactants),
+                    RedoxCouple::new(reduction.reactants, reduction.products),
+                )),
+                HalfReactionStep::BalanceHalfReaction { is_oxidation, half_reaction, .. } =>
+                    half_reactions[usize::from(!is_oxidation)] = Some(half_reaction),
+                _ => {},
+            }
+        }
+
+        let (Some((oxidation_couple, reduction_couple)), [Some(oxidation), Some(reduction)]) =
+            (couples, half_reactions) else {
+            return Err(BalancerError::HalfReactionsAreNotFound);
+        };
+
+        Ok(Self {
+            medium,
+            ionic_equation,
+            oxidation_couple,
+            reduction_couple,
+            oxidation,
+            reduction,
+        })
+    }
+
+    /// Returns the species of a side, with the compounds dissociated into their ions.
+    fn get_ions(entities: &[Entity]) -> Vec<Entity> {
+        entities.iter()
+            .flat_map(|x| match Dissociation::new(x) {
+                Some(dissociation) => dissociation.get_ions().map(|(y, _)| y).to_vec(),
+                None => vec![x.clone()],
+            })
+            .unique()
+            .collect()
+    }
+
+    /// Returns the different oxidation states of the element in the species.
+    fn get_states(entities: &[Entity], element_name: &str) -> Vec<Ratio<i32>> {
+        entities.iter()
+            .filter_map(|x| x.get_oxidation_states().get_state(element_name))
+            .unique()
+            .collect()
+    }
+
+    /// Checks whether the species of a side contains an element in a state that changes: a
+    /// state absent from the other side, or a state that is the only one of its side while
+    /// the other side has a new one.
+    fn is_redox_species(entity: &Entity, side: &[Entity], other_side: &[Entity]) -> bool {
+        let states = entity.get_oxidation_states();
+
+        states.states.iter().any(|x| {
+            let side_states = Self::get_states(side, &x.element_name);
+            let other_side_states = Self::get_states(other_side, &x.element_name);
+
+            !other_side_states.contains(&x.value)
+                || side_states.iter().all(|y| other_side_states.contains(y))
+                    && other_side_states.iter().any(|y| !side_states.contains(y))
+        })
+    }
+
+    /// Returns the skeleton ionic equation of the species, or the ions of the dissociated
+    /// compounds, taking part in the electron transfer.
+    fn get_ionic_equation(equation: &Equation) -> Equation {
+        let reactants = Self::get_ions(&equation.reactants);
+        let products = Self::get_ions(&equation.products);
+
+        Equation::new(
+            reactants.iter()
+                .filter(|x| Self::is_redox_species(x, &reactants, &products))
+                .cloned()
+                .collect(),
+            products.iter()
+                .filter(|x| Self::is_redox_species(x, &products, &reactants))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Formats the couples and the half-reactions, one per line.
+    pub fn format(&self) -> String {
+        [
+            format!("Oxidation couple: {}", self.oxidation_couple.format()),
+            format!("Reduction couple: {}", self.reduction_couple.format()),
+            format!("Oxidation ({} medium): {}", self.medium.get_name(), self.oxidation.format()),
+            format!("Reduction ({} medium): {}", self.medium.get_name(), self.reduction.format()),
+        ].join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        balancer_error::BalancerError,
+        half_reactions::HalfReactions,
+        medium::Medium,
+        parser::Parser,
+    };
+
+    fn get_half_reactions(equation: &str, medium: Medium) -> Result<HalfReactions, BalancerError> {
+        HalfReactions::new(&Parser::new(equation).parse_equation().unwrap(), medium)
+    }
+
+    #[test]
+    fn test_new() {
+        let half_reactions = get_half_reactions(
+            "KMnO4 + FeSO4 + H2SO4 = MnSO4 + Fe2(SO4)3 + K2SO4 + H2O",
+            Medium::Acidic,
+        ).unwrap();
+        assert_eq!(
+            half_reactions.ionic_equation.format(&[]),
+            "MnO4{\u{2212}} + Fe{2+} = Mn{2+} + Fe{3+}"
+        );
+        assert_eq!(half_reactions.oxidation_couple.format(), "Fe{3+}/Fe{2+}");
+        assert_eq!(half_reactions.reduction_couple.format(), "MnO4{\u{2212}}/Mn{2+}");
+        assert_eq!(half_reactions.oxidation.format(), "Fe{2+} = Fe{3+} + e\u{2212}");
+        assert_eq!(
+            half_reactions.reduction.format(),
+            "MnO4{\u{2212}} + 8\u{a0}H{+} + 5\u{a0}e\u{2212} = Mn{2+} + 4\u{a0}H2O"
+        );
+
+        let half_reactions =
+            get_half_reactions("Cu + HNO3 = Cu(NO3)2 + NO + H2O", Medium::Acidic).unwrap();
+        assert_eq!(half_reactions.ionic_equation.format(&[]), "Cu + NO3{\u{2212}} = Cu{2+} + NO");
+
+        let half_reactions =
+            get_half_reactions("K2Cr2O7 + HCl = KCl + CrCl3 + Cl2 + H2O", Medium::Acidic).unwrap();
+        assert_eq!(half_reactions.oxidation_couple.format(), "Cl2/Cl{\u{2212}}");
+        assert_eq!(
+            half_reactions.reduction.format(),
+            "Cr2O7{2\u{2212}} + 14\u{a0}H{+} + 6\u{a0}e\u{2212} = 2\u{a0}Cr{3+} + 7\u{a0}H2O"
+        );
+
+        assert!(matches!(
+            get_half_reactions("NaOH + HCl = NaCl + H2O", Medium::Acidic),
+            Err(BalancerError::ElectronsAreNotTransferred)
+        ));
+    }
+
+    #[test]
+    fn test_format() {
+        let half_reactions = get_half_reactions(
+            "Cl2 + NaOH = NaCl + NaClO3 + H2O",
+            Medium::Basic,
+        ).unwrap();
+        assert_eq!(
+            half_reactions.format(),
+            "Oxidation couple: ClO3{\u{2212}}/Cl2\n\
+                Reduction couple: Cl2/Cl{\u{2212}}\n\
+                Oxidation (basic medium): Cl2 + 12\u{a0}OH{\u{2212}} = 2\u{a0}ClO3{\u{2212}} + 10\u{a0}e\u{2212} \
+                    + 6\u{a0}H2O\n\
+                Reduction (basic medium): Cl2 + 2\u{a0}e\u{2212} = 2\u{a0}Cl{\u{2212}}"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::{collections::{HashSet, hash_map::RandomState}, hash::{Hash, Hasher}};
+use crate::{composition::Composition, item::Item, span::Span};
+
+/// The part of a formula after a dot, such as `·5H2O` in `CuSO4·5H2O`.
+#[derive(Debug, Clone)]
+pub struct Hydrate {
+    /// Items
+    pub items: Vec<Item>,
+    /// Count
+    pub count: u8,
+    /// Span in the input
+    pub span: Span,
+}
+
+impl Hydrate {
+    /// Hydrate constructor.
+    pub fn new(items: Vec<Item>, count: u8) -> Self {
+        Self { items, count, span: Span::default() }
+    }
+
+    /// Sets the span in the input.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Adds the element name to set of element names.
+    pub fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>) {
+        for x in &self.items {
+            x.add_to_elements_names(elements_names);
+        }
+    }
+
+    /// Counts the number of atoms of element by its name.
+    pub fn count_element_by_name(&self, element_name: &str) -> u32 {
+        u32::from(self.count)
+            * self.items.iter().fold(0, |sum, x| sum + x.count_element_by_name(element_name))
+    }
+
+    /// Adds the atoms of items multiplied by the factor to the composition.
+    pub fn add_to_composition(&self, composition: &mut Composition, factor: i32) {
+        for x in &self.items {
+            x.add_to_composition(composition, i32::from(self.count) * factor);
+        }
+    }
+
+    /// Formats a hydrate part.
+    pub fn format(&self) -> String {
+        let mut result = "\u{b7}".to_string();
+
+        if self.count != 1 {
+            result += &self.count.to_string();
+        }
+
+        for x in &self.items {
+            result += &x.format();
+        }
+
+        result
+    }
+
+    /// Formats a hydrate part in LaTeX.
+    pub fn format_latex(&self) -> String {
+        let mut result = "\\cdot ".to_string();
+
+        if self.count != 1 {
+            result += &self.count.to_string();
+        }
+
+        for x in &self.items {
+            result += &x.format_latex();
+        }
+
+        result
+    }
+}
+
+impl PartialEq for Hydrate {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.count == other.count
+    }
+}
+
+impl Eq for Hydrate {}
+
+impl Hash for Hydrate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.items.hash(state);
+        self.count.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::{composition::Composition, element::Element, hydrate::Hydrate, item::Item};
+
+    /// Returns the water hydrate part.
+    fn get_water(count: u8) -> Hydrate {
+        let items: Vec<Item> = vec![
+            Element::new("H".to_string(), 2).into(),
+            Element::new("O".to_string(), 1).into(),
+        ];
+
+        Hydrate::new(items, count)
+    }
+
+    #[test]
+    fn test_new() {
+        let hydrate = get_water(5);
+        assert_eq!(hydrate.count, 5);
+        assert_eq!(hydrate.items.len(), 2);
+    }
+
+    #[test]
+    fn test_add_to_elements_names() {
+        let mut elements_names = HashSet::new();
+        get_water(5).add_to_elements_names(&mut elements_names);
+        assert_eq!(elements_names, HashSet::from(["H".to_string(), "O".to_string()]));
+    }
+
+    #[test]
+    fn test_count_element_by_name() {
+        assert_eq!(get_water(5).count_element_by_name("H"), 10);
+        assert_eq!(get_water(5).count_element_by_name("O"), 5);
+    }
+
+    #[test]
+    fn test_add_to_composition() {
+        let mut composition = Composition::default();
+        get_water(5).add_to_composition(&mut composition, 2);
+        assert_eq!(composition.elements, [("H".to_string(), 20), ("O".to_string(), 10)]);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(get_water(5).format(), "\u{b7}5H2O");
+        assert_eq!(get_water(1).format(), "\u{b7}H2O");
+    }
+
+    #[test]
+    fn test_format_latex() {
+        assert_eq!(get_water(5).format_latex(), "\\cdot 5H_{2}O");
+    }
+}
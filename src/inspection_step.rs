@@ -0,0 +1,73 @@
+use itertools::Itertools;
+use num::rational::Ratio;
+use crate::entity::Entity;
+
+/// A step of balancing by inspection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InspectionStep {
+    /// The coefficient of the most complex species is set to 1.
+    StartWith { entity: Entity },
+    /// The element, or the charge if the name is "charge", is balanced by the coefficient
+    /// of the only species left without one.
+    BalanceElement { element_name: String, entity: Entity, coefficient: Ratio<i32> },
+    /// The coefficients are multiplied by the factor to clear the fractions.
+    ClearFractions { factor: i32, coefficients: Vec<i32> },
+    /// Inspection cannot finish and the matrix method is used instead.
+    FallBackToMatrix,
+}
+
+impl InspectionStep {
+    /// Returns the description.
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::StartWith { entity } => format!(
+                "Start with the most complex species, {}, and set its coefficient to 1.",
+                entity.format(),
+            ),
+            Self::BalanceElement { element_name, entity, coefficient } => format!(
+                "Balance {} with {}: its coefficient is {}.",
+                if element_name == "charge" { "the charge" } else { element_name },
+                entity.format(),
+                coefficient,
+            ),
+            Self::ClearFractions { factor, coefficients } => if *factor == 1 {
+                format!(
+                    "All the coefficients are integers: {}.",
+                    coefficients.iter().join(", "),
+                )
+            } else {
+                format!(
+                    "Multiply by {factor} to clear the fractions: {}.",
+                    coefficients.iter().join(", "),
+                )
+            },
+            Self::FallBackToMatrix =>
+                "Inspection cannot determine the remaining coefficients: the matrix method is \
+                    used instead.".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::{entity::Entity, inspection_step::InspectionStep};
+
+    #[test]
+    fn test_get_description() {
+        let entity = "O2".parse::<Entity>().unwrap();
+        assert_eq!(
+            InspectionStep::BalanceElement {
+                element_name: "O".to_string(),
+                entity,
+                coefficient: Ratio::new(1, 2),
+            }.get_description(),
+            "Balance O with O2: its coefficient is 1/2."
+        );
+        assert_eq!(
+            InspectionStep::ClearFractions { factor: 2, coefficients: vec![2, 1, 2] }
+                .get_description(),
+            "Multiply by 2 to clear the fractions: 2, 1, 2."
+        );
+    }
+}
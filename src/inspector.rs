@@ -0,0 +1,201 @@
+use num::{integer::lcm, rational::Ratio, Zero};
+use crate::{
+    balancer::Balancer,
+    composition::Composition,
+    element_data::ElementData,
+    equation::Equation,
+    inspection_step::InspectionStep,
+};
+
+/// Balances an equation by inspection: starting with the most complex species, it balances
+/// the metals, then the other nonmetals, then hydrogen, oxygen and the charge.
+pub struct Inspector {
+    /// Equation
+    equation: Equation,
+    /// Compositions of the reactants followed by the products
+    compositions: Vec<Composition>,
+    /// Steps
+    pub steps: Vec<InspectionStep>,
+}
+
+impl Inspector {
+    /// Inspector constructor.
+    pub fn new(equation: &Equation) -> Self {
+        Self {
+            equation: equation.clone(),
+            compositions: equation.get_compositions(),
+            steps: vec![],
+        }
+    }
+
+    /// Returns the index of the species with the most atoms, then with the most elements.
+    fn get_most_complex_species_index(&self) -> usize {
+        let mut index = 0;
+        let mut complexity = (0, 0);
+
+        for (i, x) in self.compositions.iter().enumerate() {
+            let atoms_count = x.elements.iter().map(|(_, count)| count).sum::<i32>();
+            let current_complexity = (atoms_count, x.elements.len());
+
+            if current_complexity > complexity {
+                index = i;
+                complexity = current_complexity;
+            }
+        }
+
+        index
+    }
+
+    /// Returns the names of the elements in the order of balancing: the metals, the other
+    /// nonmetals, hydrogen, oxygen, then "charge" if any species is charged.
+    fn get_elements_names_in_order(&self) -> Vec<String> {
+        let get_rank = |name: &str| match name {
+            "H" => 2,
+            "O" => 3,
+            _ if ElementData::find(name).is_some_and(|x| !x.is_metal) => 1,
+            _ => 0,
+        };
+        let mut elements_names: Vec<String> = Balancer::get_rows_names(&self.compositions)
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect();
+
+        elements_names.sort_by_key(|x| get_rank(x));
+
+        if self.compositions.iter().any(|x| x.charge != 0) {
+            elements_names.push("charge".to_string());
+        }
+
+        elements_names
+    }
+
+    /// Returns the signed counts of the element, or of the charge, in every species.
+    fn get_counts(&self, element_name: &str) -> Vec<i32> {
+        let reactants_count = self.equation.reactants.len();
+
+        self.compositions.iter().enumerate().map(|(j, x)| {
+            let sign = if j < reactants_count { 1 } else { -1 };
+
+            if element_name == "charge" {
+                sign * x.charge
+            } else {
+                sign * x.count_element_by_name(element_name)
+            }
+        }).collect()
+    }
+
+    /// Balances the equation, returning the coefficients, or `None` if inspection gets stuck
+    /// or runs into a contradiction.
+    pub fn balance(&mut self) -> Option<Vec<i32>> {
+        let entities: Vec<_> = self.equation.reactants.iter()
+            .chain(&self.equation.products)
+            .cloned()
+            .collect();
+        let elements_counts: Vec<(String, Vec<i32>)> = self.get_elements_names_in_order()
+            .into_iter()
+            .map(|x| {
+                let counts = self.get_counts(&x);
+                (x, counts)
+            })
+            .collect();
+        let start_index = self.get_most_complex_species_index();
+        let mut values: Vec<Option<Ratio<i32>>> = vec![None; entities.len()];
+
+        values[start_index] = Some(Ratio::from_integer(1));
+        self.steps = vec![InspectionStep::StartWith { entity: entities[start_index].clone() }];
+
+        'inspection: while values.iter().any(Option::is_none) {
+            for (element_name, counts) in &elements_counts {
+                let unknowns: Vec<usize> = (0..counts.len())
+                    .filter(|&j| counts[j] != 0 && values[j].is_none())
+                    .collect();
+
+                if unknowns.len() != 1 {
+                    continue;
+                }
+
+                let known_sum: Ratio<i32> = counts.iter().zip(&values)
+                    .filter_map(|(&count, value)| value.map(|x| x * count))
+                    .sum();
+                let coefficient = -known_sum / counts[unknowns[0]];
+
+                if coefficient <= Ratio::zero() {
+                    return None;
+                }
+
+                values[unknowns[0]] = Some(coefficient);
+                self.steps.push(InspectionStep::BalanceElement {
+                    element_name: element_name.clone(),
+                    entity: entities[unknowns[0]].clone(),
+                    coefficient,
+                });
+
+                continue 'inspection;
+            }
+
+            return None;
+        }
+
+        let values: Vec<Ratio<i32>> = values.into_iter().flatten().collect();
+
+        for (_, counts) in &elements_counts {
+            let sum: Ratio<i32> = counts.iter().zip(&values).map(|(&count, &x)| x * count).sum();
+
+            if !sum.is_zero() {
+                return None;
+            }
+        }
+
+        let factor = values.iter().fold(1, |result, x| lcm(result, *x.denom()));
+        let coefficients: Vec<i32> = values.iter().map(|x| (x * factor).to_integer()).collect();
+
+        self.steps.push(InspectionStep::ClearFractions {
+            factor,
+            coefficients: coefficients.clone(),
+        });
+
+        Some(coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{inspection_step::InspectionStep, inspector::Inspector, parser::Parser};
+
+    fn get_inspector(equation: &str) -> Inspector {
+        Inspector::new(&Parser::new(equation).parse_equation().unwrap())
+    }
+
+    #[test]
+    fn test_get_most_complex_species_index() {
+        let inspector = get_inspector("C3H8 + O2 = CO2 + H2O");
+        assert_eq!(inspector.get_most_complex_species_index(), 0);
+    }
+
+    #[test]
+    fn test_get_elements_names_in_order() {
+        let inspector = get_inspector("Fe2O3 + H2SO4 = Fe2(SO4)3 + H2O");
+        assert_eq!(inspector.get_elements_names_in_order(), ["Fe", "S", "H", "O"]);
+
+        let inspector = get_inspector("Fe{3+} + e = Fe{2+}");
+        assert_eq!(inspector.get_elements_names_in_order(), ["Fe", "charge"]);
+    }
+
+    #[test]
+    fn test_balance() {
+        let mut inspector = get_inspector("C3H8 + O2 = CO2 + H2O");
+        assert_eq!(inspector.balance(), Some(vec![1, 5, 3, 4]));
+        assert_eq!(inspector.steps.len(), 5);
+        assert_eq!(
+            inspector.steps[1].get_description(),
+            "Balance C with CO2: its coefficient is 3."
+        );
+
+        let mut inspector = get_inspector("H2 + O2 = H2O");
+        assert_eq!(inspector.balance(), Some(vec![2, 1, 2]));
+
+        let mut inspector = get_inspector("Cu + HNO3 = Cu(NO3)2 + NO + H2O");
+        assert_eq!(inspector.balance(), None);
+        assert!(matches!(inspector.steps.first(), Some(InspectionStep::StartWith { .. })));
+    }
+}
@@ -1,11 +1,112 @@
-use std::collections::HashSet;
-use std::collections::hash_map::RandomState;
+use std::collections::{HashSet, hash_map::RandomState};
+use crate::{
+    composition::Composition,
+    element::Element,
+    group::Group,
+    span::Span,
+    visitor::Visitor,
+};
 
-pub trait Item {
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Item {
+    Element(Element),
+    Group(Group),
+}
+
+impl Item {
     /// Adds the element name to set of element names.
-    fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>);
+    pub fn add_to_elements_names(&self, elements_names: &mut HashSet<String, RandomState>) {
+        match self {
+            Self::Element(x) => x.add_to_elements_names(elements_names),
+            Self::Group(x) => x.add_to_elements_names(elements_names),
+        }
+    }
+
     /// Counts the number of atoms of element by its name.
-    fn count_element_by_name(&self, element_name: &str) -> u32;
+    pub fn count_element_by_name(&self, element_name: &str) -> u32 {
+        match self {
+            Self::Element(x) => x.count_element_by_name(element_name),
+            Self::Group(x) => x.count_element_by_name(element_name),
+        }
+    }
+
+    /// Adds the atoms multiplied by the factor to the composition.
+    pub fn add_to_composition(&self, composition: &mut Composition, factor: i32) {
+        match self {
+            Self::Element(x) => x.add_to_composition(composition, factor),
+            Self::Group(x) => x.add_to_composition(composition, factor),
+        }
+    }
+
     /// Formats an item.
-    fn format(&self) -> String;
+    pub fn format(&self) -> String {
+        match self {
+            Self::Element(x) => x.format(),
+            Self::Group(x) => x.format(),
+        }
+    }
+
+    /// Formats an item in LaTeX.
+    pub fn format_latex(&self) -> String {
+        match self {
+            Self::Element(x) => x.format_latex(),
+            Self::Group(x) => x.format_latex(),
+        }
+    }
+
+    /// Returns the span in the input.
+    pub fn get_span(&self) -> Span {
+        match self {
+            Self::Element(x) => x.span,
+            Self::Group(x) => x.span,
+        }
+    }
+
+    /// Accepts a visitor.
+    pub fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_item(self);
+    }
+}
+
+impl From<Element> for Item {
+    fn from(element: Element) -> Self {
+        Self::Element(element)
+    }
+}
+
+impl From<Group> for Item {
+    fn from(group: Group) -> Self {
+        Self::Group(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::{element::Element, group::Group, item::Item, span::Span};
+
+    #[test]
+    fn test_add_to_elements_names() {
+        let mut elements_names = HashSet::new();
+        let item = Item::from(Group::new(vec![Element::new("O".to_string(), 1).into()], 2));
+        item.add_to_elements_names(&mut elements_names);
+        assert_eq!(elements_names, HashSet::from(["O".to_string()]));
+    }
+
+    #[test]
+    fn test_count_element_by_name() {
+        let item = Item::from(Group::new(vec![Element::new("O".to_string(), 2).into()], 3));
+        assert_eq!(item.count_element_by_name("O"), 6);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Item::from(Element::new("H".to_string(), 2)).format(), "H2");
+    }
+
+    #[test]
+    fn test_get_span() {
+        let item = Item::from(Element::new("H".to_string(), 2).with_span(Span::new(0, 2)));
+        assert_eq!(item.get_span(), Span::new(0, 2));
+    }
 }
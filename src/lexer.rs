@@ -0,0 +1,168 @@
+use crate::{charge_style::ChargeStyle, span::Span, token::Token, token_kind::TokenKind};
+
+pub struct Lexer<'eq> {
+    input: &'eq str,
+    position: usize,
+}
+
+impl<'eq> Lexer<'eq> {
+    /// Lexer constructor.
+    pub fn new(input: &'eq str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    /// Splits the input into tokens, skipping the spaces.
+    pub fn tokenize(input: &'eq str) -> Vec<Token<'eq>> {
+        Self::new(input).collect()
+    }
+
+    /// Returns the remaining input.
+    fn get_substring(&self) -> &'eq str {
+        &self.input[self.position..]
+    }
+
+    /// Skips the spaces.
+    fn skip_spaces(&mut self) {
+        let substring = self.get_substring();
+        self.position += substring.len() - substring.trim_start().len();
+    }
+
+    /// Returns the length of the phase, such as `(aq)`, at the start of the remaining input.
+    fn scan_phase(&self) -> Option<usize> {
+        let substring = self.get_substring();
+        let letters_count = substring[1..].bytes().take_while(u8::is_ascii_lowercase).count();
+
+        if letters_count > 0 && substring[1 + letters_count..].starts_with(')') {
+            Some(letters_count + 2)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the length of the superscript charge, such as `²⁺`, at the start of the
+    /// remaining input: the superscript digits followed by the superscript sign, if any.
+    fn scan_superscript_charge(&self) -> usize {
+        let substring = self.get_substring();
+        let digits_length: usize = substring.chars()
+            .take_while(|&x| ChargeStyle::parse_superscript_digit(x).is_some())
+            .map(char::len_utf8)
+            .sum();
+
+        match substring[digits_length..].chars().next() {
+            Some(x @ ('\u{207a}' | '\u{207b}')) => digits_length + x.len_utf8(),
+            _ => digits_length,
+        }
+    }
+
+    /// Returns the length of the token kind at the start of the remaining input.
+    fn scan_token(&self) -> Option<(TokenKind, usize)> {
+        let substring = self.get_substring();
+        let first_char = substring.chars().next()?;
+        let count_bytes = |predicate: fn(&char) -> bool| {
+            substring.chars().skip(1).take_while(predicate).map(char::len_utf8).sum::<usize>()
+        };
+
+        Some(match first_char {
+            'A'..='Z' => (TokenKind::Symbol, 1 + count_bytes(char::is_ascii_lowercase)),
+            '0'..='9' => (TokenKind::Number, 1 + count_bytes(char::is_ascii_digit)),
+            'e' => (TokenKind::Electron, 1),
+            '+' => (TokenKind::PlusSign, 1),
+            '-' | '\u{2212}' => (TokenKind::MinusSign, first_char.len_utf8()),
+            '=' => (TokenKind::EqualSign, 1),
+            '(' => match self.scan_phase() {
+                Some(x) => (TokenKind::Phase, x),
+                None => (TokenKind::OpeningBracket, 1),
+            },
+            ')' => (TokenKind::ClosingBracket, 1),
+            '[' => (TokenKind::OpeningSquareBracket, 1),
+            ']' => (TokenKind::ClosingSquareBracket, 1),
+            '.' | '*' | '\u{b7}' | '\u{2022}' => (TokenKind::Dot, first_char.len_utf8()),
+            '{' => (TokenKind::OpeningCurlyBracket, 1),
+            '^' => (TokenKind::Caret, 1),
+            '\u{b9}' | '\u{b2}' | '\u{b3}' | '\u{2070}' | '\u{2074}'..='\u{2079}'
+            | '\u{207a}' | '\u{207b}' =>
+                (TokenKind::SuperscriptCharge, self.scan_superscript_charge()),
+            '}' => (TokenKind::ClosingCurlyBracket, 1),
+            _ => (TokenKind::Invalid, first_char.len_utf8()),
+        })
+    }
+}
+
+impl<'eq> Iterator for Lexer<'eq> {
+    type Item = Token<'eq>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_spaces();
+
+        let (kind, length) = self.scan_token()?;
+        let span = Span::new(self.position, self.position + length);
+        self.position = span.end;
+
+        Some(Token::new(kind, &self.input[span.start..span.end], span))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lexer::Lexer, span::Span, token_kind::TokenKind};
+
+    #[test]
+    fn test_new() {
+        let lexer = Lexer::new("H2 + O2 = H2O");
+        assert_eq!(lexer.input, "H2 + O2 = H2O");
+        assert_eq!(lexer.position, 0);
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = Lexer::tokenize(" Fe2(SO4)3 + e\u{2212} = Cl{-}\u{a0}$");
+        assert_eq!(
+            tokens.iter().map(|x| x.text).collect::<Vec<&str>>(),
+            [
+                "Fe", "2", "(", "S", "O", "4", ")", "3", "+", "e", "\u{2212}", "=",
+                "Cl", "{", "-", "}", "$",
+            ]
+        );
+        assert_eq!(tokens[0].span, Span::new(1, 3));
+        assert_eq!(tokens[10].kind, TokenKind::MinusSign);
+        assert_eq!(tokens[10].span, Span::new(14, 17));
+        assert_eq!(tokens[16].kind, TokenKind::Invalid);
+    }
+
+    #[test]
+    fn test_skip_spaces() {
+        let mut lexer = Lexer::new(" \u{a0} H2");
+        lexer.skip_spaces();
+        assert_eq!(lexer.position, 4);
+    }
+
+    #[test]
+    fn test_scan_phase() {
+        assert_eq!(Lexer::new("(s)").scan_phase(), Some(3));
+        assert_eq!(Lexer::new("(aq").scan_phase(), None);
+        assert_eq!(Lexer::new("(Aq)").scan_phase(), None);
+    }
+
+    #[test]
+    fn test_scan_superscript_charge() {
+        assert_eq!(Lexer::new("\u{207b}").scan_superscript_charge(), 3);
+        assert_eq!(Lexer::new("\u{b9}\u{2074}\u{207b}").scan_superscript_charge(), 8);
+        assert_eq!(Lexer::new("\u{b3}").scan_superscript_charge(), 2);
+    }
+
+    #[test]
+    fn test_scan_token() {
+        assert_eq!(Lexer::new("Na2").scan_token(), Some((TokenKind::Symbol, 2)));
+        assert_eq!(Lexer::new("12}").scan_token(), Some((TokenKind::Number, 2)));
+        assert_eq!(Lexer::new("\u{2192}").scan_token(), Some((TokenKind::Invalid, 3)));
+        assert_eq!(Lexer::new("(aq) + H").scan_token(), Some((TokenKind::Phase, 4)));
+        assert_eq!(Lexer::new("(OH)2").scan_token(), Some((TokenKind::OpeningBracket, 1)));
+        assert_eq!(Lexer::new("\u{b7}5H2O").scan_token(), Some((TokenKind::Dot, 2)));
+        assert_eq!(Lexer::new("^2+").scan_token(), Some((TokenKind::Caret, 1)));
+        assert_eq!(
+            Lexer::new("\u{b2}\u{207a} + H").scan_token(),
+            Some((TokenKind::SuperscriptCharge, 5))
+        );
+        assert_eq!(Lexer::new("").scan_token(), None);
+    }
+}
@@ -0,0 +1,55 @@
+pub mod added_species;
+pub mod algebraic_solution;
+pub mod algebraic_step;
+pub mod balanced_equation;
+pub mod balancer;
+pub mod balancer_error;
+pub mod balancer_warning;
+pub mod balancing_step;
+pub mod balancing_strategy;
+pub mod bracket;
+pub mod cell_potential;
+pub mod charge_style;
+pub mod composition;
+pub mod dissociation;
+pub mod electrolyte;
+pub mod element;
+pub mod element_data;
+pub mod entity;
+pub mod equation;
+pub mod explanation;
+pub mod group;
+pub mod half_reaction_solution;
+pub mod half_reaction_stage;
+pub mod half_reaction_step;
+pub mod half_reactions;
+pub mod hydrate;
+pub mod inspection_step;
+pub mod inspector;
+pub mod item;
+pub mod lexer;
+pub mod matrix;
+pub mod matrix_operation;
+pub mod medium;
+pub mod net_ionic_equation;
+pub mod notation;
+pub mod oxidation_change;
+pub mod oxidation_rule;
+pub mod oxidation_state;
+pub mod oxidation_states;
+pub mod parsed_equation;
+pub mod parser;
+pub mod parser_error;
+pub mod phase;
+pub mod precipitation;
+pub mod redox_analysis;
+pub mod redox_couple;
+pub mod removal_reason;
+pub mod removed_species;
+pub mod sides;
+pub mod solubility;
+pub mod span;
+pub mod standard_potential;
+pub mod token;
+pub mod token_kind;
+pub mod visitor;
@@ -1,24 +1,311 @@
-mod balancer;
-mod balancer_error;
-mod element;
-mod entity;
-mod equation;
-mod group;
-mod item;
-mod matrix;
-mod parser;
-mod parser_error;
-mod regular_expression;
-
-use std::env;
-use crate::balancer::Balancer;
+use std::{env, fs, process};
+use chemical_equation_balancer::{
+    algebraic_solution::AlgebraicSolution,
+    balancer::Balancer,
+    balancer_error::BalancerError,
+    balancing_strategy::BalancingStrategy,
+    cell_potential::{CellPotential, STANDARD_TEMPERATURE},
+    charge_style::ChargeStyle,
+    entity::Entity,
+    equation::Equation,
+    half_reaction_solution::HalfReactionSolution,
+    half_reactions::HalfReactions,
+    medium::Medium,
+    net_ionic_equation::NetIonicEquation,
+    notation::Notation,
+    parser::Parser,
+    precipitation::Precipitation,
+};
+
+/// Rendering of the balancing steps.
+#[derive(Clone, Copy)]
+enum ExplanationFormat {
+    Text,
+    Latex,
+}
+
+/// Command line options.
+#[derive(Default)]
+struct Options {
+    /// Equation
+    equation: Option<String>,
+    /// Path to the file of equations
+    file: Option<String>,
+    /// Notation of the formulas in the balanced equations
+    notation: Notation,
+    /// Style of the charges in the balanced equations
+    charge_style: ChargeStyle,
+    /// Whether the species on both sides are cancelled
+    is_cancellation_enabled: bool,
+    /// Whether the species with negative coefficients are moved to the other side
+    is_moving_enabled: bool,
+    /// Strategy of finding the coefficients
+    strategy: BalancingStrategy,
+    /// Medium of the half-reaction method, if it is used instead of the balancer
+    medium: Option<Medium>,
+    /// Medium of the half-reactions into which the equation is split, if they are printed
+    split_medium: Option<Medium>,
+    /// Whether the complete and net ionic equations are printed
+    is_net_ionic_enabled: bool,
+    /// Aqueous compounds whose double-displacement reaction is predicted instead of balancing
+    precipitation_reactants: Option<(Entity, Entity)>,
+    /// Medium of the galvanic cell whose potential is printed
+    potential_medium: Option<Medium>,
+    /// Concentrations and partial pressures of the species for the Nernst equation
+    activities: Vec<(Entity, f64)>,
+    /// Temperature in kelvins for the Nernst equation
+    temperature: Option<f64>,
+    /// Medium whose water and ions complete the equations that do not balance as written
+    completion_medium: Option<Medium>,
+    /// Whether the redox analysis is printed
+    is_redox_analysis_enabled: bool,
+    /// Rendering of the balancing steps, if they are printed
+    explanation_format: Option<ExplanationFormat>,
+    /// Rendering of the derivation by the algebraic method, if it is printed
+    algebraic_format: Option<ExplanationFormat>,
+}
+
+impl Options {
+    /// Parses the command line arguments.
+    fn parse(arguments: impl Iterator<Item = String>) -> Self {
+        let mut options = Self::default();
+        let mut arguments = arguments.skip(1);
+
+        while let Some(argument) = arguments.next() {
+            match argument.as_str() {
+                "--file" | "-f" => options.file = Some(arguments.next().expect("No file.")),
+                "--hill" => options.notation = Notation::Hill,
+                "--charge" => options.charge_style = match arguments.next().as_deref() {
+                    Some("braces") => ChargeStyle::Braces,
+                    Some("superscript") => ChargeStyle::Superscript,
+                    Some("caret") => ChargeStyle::Caret,
+                    _ => panic!("Unknown charge style: expected braces, superscript or caret."),
+                },
+                "--strategy" => options.strategy = match arguments.next().as_deref() {
+                    Some("matrix") => BalancingStrategy::Matrix,
+                    Some("inspection") => BalancingStrategy::Inspection,
+                    _ => panic!("Unknown strategy: expected matrix or inspection."),
+                },
+                "--cancel" => options.is_cancellation_enabled = true,
+                "--move" => options.is_moving_enabled = true,
+                "--redox" => options.is_redox_analysis_enabled = true,
+                "--half-reactions" => options.medium = match arguments.next().as_deref() {
+                    Some("acidic") => Some(Medium::Acidic),
+                    Some("basic") => Some(Medium::Basic),
+                    _ => panic!("Unknown medium: expected acidic or basic."),
+                },
+                "--split" => options.split_medium = match arguments.next().as_deref() {
+                    Some("acidic") => Some(Medium::Acidic),
+                    Some("basic") => Some(Medium::Basic),
+                    _ => panic!("Unknown medium: expected acidic or basic."),
+                },
+                "--net-ionic" => options.is_net_ionic_enabled = true,
+                "--precipitation" => {
+                    let mut parse_reactant = || arguments.next()
+                        .and_then(|x| x.parse::<Entity>().ok())
+                        .expect("Two valid formulas of the reactants are expected.");
+                    options.precipitation_reactants = Some((parse_reactant(), parse_reactant()));
+                },
+                "--potential" => options.potential_medium = match arguments.next().as_deref() {
+                    Some("acidic") => Some(Medium::Acidic),
+                    Some("basic") => Some(Medium::Basic),
+                    _ => panic!("Unknown medium: expected acidic or basic."),
+                },
+                "--activity" => {
+                    let activity = arguments.next().expect("No activity.");
+                    let (formula, value) = activity.split_once('=')
+                        .expect("The activity is expected as a formula and a value: Cu{2+}=0.01.");
+                    options.activities.push((
+                        formula.parse().expect("Invalid formula of the activity."),
+                        value.parse().expect("Invalid value of the activity."),
+                    ));
+                },
+                "--temperature" => options.temperature = Some(
+                    arguments.next().and_then(|x| x.parse().ok()).expect("No temperature."),
+                ),
+                "--complete" => options.completion_medium = match arguments.next().as_deref() {
+                    Some("acidic") => Some(Medium::Acidic),
+                    Some("basic") => Some(Medium::Basic),
+                    Some("neutral") => Some(Medium::Neutral),
+                    _ => panic!("Unknown medium: expected acidic, basic or neutral."),
+                },
+                "--explain" | "--explain=text" =>
+                    options.explanation_format = Some(ExplanationFormat::Text),
+                "--explain=latex" => options.explanation_format = Some(ExplanationFormat::Latex),
+                "--algebraic" | "--algebraic=text" =>
+                    options.algebraic_format = Some(ExplanationFormat::Text),
+                "--algebraic=latex" => options.algebraic_format = Some(ExplanationFormat::Latex),
+                _ => options.equation = Some(argument),
+            }
+        }
+
+        options
+    }
+
+    /// Creates a balancer of the parsed equation with the options.
+    fn create_balancer(&self, equation: Equation) -> Balancer {
+        Balancer::from_equation(equation)
+            .with_notation(self.notation)
+            .with_charge_style(self.charge_style)
+            .with_cancellation(self.is_cancellation_enabled)
+            .with_moving(self.is_moving_enabled)
+            .with_strategy(self.strategy)
+            .with_completion(self.completion_medium)
+            .with_explanation(self.explanation_format.is_some())
+    }
+
+    /// Balances the equation, printing the diagnostics to the standard error.
+    fn balance(&self, equation: Equation) -> Result<String, BalancerError> {
+        if let Some(medium) = self.medium {
+            let solution = HalfReactionSolution::new(&equation, medium)?;
+            println!("{}\n", solution.format());
+
+            return Ok(solution.balanced_equation
+                .format_with_charge_style(self.notation, self.charge_style));
+        }
+
+        if let Some(medium) = self.split_medium {
+            println!("{}\n", HalfReactions::new(&equation, medium)?.format());
+        }
+
+        if self.is_net_ionic_enabled {
+            println!("{}\n", NetIonicEquation::new(&equation)?.format());
+        }
+
+        if let Some(medium) = self.potential_medium {
+            let cell_potential = CellPotential::new(&equation, medium)?;
+            println!("{}", cell_potential.format());
+
+            if !self.activities.is_empty() || self.temperature.is_some() {
+                let temperature = self.temperature.unwrap_or(STANDARD_TEMPERATURE);
+                println!(
+                    "E = {:.4} V at {temperature} K (Q = {})",
+                    cell_potential.get_potential(&self.activities, temperature)?,
+                    cell_potential.get_reaction_quotient(&self.activities)?,
+                );
+            }
+
+            println!();
+        }
+
+        if let Some(format) = self.algebraic_format {
+            let solution = AlgebraicSolution::new(&equation)?;
+
+            match format {
+                ExplanationFormat::Text => println!("{}\n", solution.format_text()),
+                ExplanationFormat::Latex => println!("{}\n", solution.format_latex()),
+            }
+        }
+
+        let balanced_equation = self.create_balancer(equation).balance()?;
+
+        for x in &balanced_equation.warnings {
+            eprintln!("Warning: {}", x.get_description());
+        }
+
+        for x in &balanced_equation.removed_species {
+            eprintln!("Removed {}: {}", x.reactant.format(), x.reason.get_description());
+        }
+
+        for x in &balanced_equation.added_species {
+            eprintln!("Completed: {}", x.get_description());
+        }
+
+        for (i, x) in balanced_equation.inspection_steps.iter().enumerate() {
+            println!("{}. {}", i + 1, x.get_description());
+        }
+
+        if let (Some(format), Some(explanation)) =
+            (self.explanation_format, &balanced_equation.explanation) {
+            match format {
+                ExplanationFormat::Text => println!("{}\n", explanation.format_text()),
+                ExplanationFormat::Latex => println!("{}\n", explanation.format_latex()),
+            }
+        }
+
+        if self.is_redox_analysis_enabled {
+            println!("{}\n", balanced_equation.get_redox_analysis().format());
+        }
+
+        Ok(balanced_equation.format_with_charge_style(self.notation, self.charge_style))
+    }
+}
+
+/// Balances every equation of a file and prints the results.
+fn balance_file(options: &Options, path: &str) {
+    let input = fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("Unable to read {path}: {error}"));
+    let mut has_errors = false;
+
+    for parsed_equation in Parser::parse_equations(&input) {
+        let (line, column) = (parsed_equation.line, parsed_equation.column);
+
+        for x in &parsed_equation.warnings {
+            eprintln!("{line}:{column}: Warning: {x}");
+        }
+
+        let (error_line, error_column) = parsed_equation.get_error_position()
+            .unwrap_or((line, column));
+        let result = match parsed_equation.result {
+            Ok(equation) => options.balance(equation).map_err(|balancer_error| format!(
+                "{error_line}:{error_column}: {}",
+                balancer_error.get_description(),
+            )),
+            Err(ref parser_error) => Err(format!(
+                "{error_line}:{error_column}:\n{}",
+                parser_error.get_diagnostic(&parsed_equation.source),
+            )),
+        };
+
+        match result {
+            Ok(balanced_equation) => println!("{line}:{column}: {balanced_equation}"),
+            Err(message) => {
+                has_errors = true;
+                eprintln!("{message}");
+            },
+        }
+    }
+
+    if has_errors {
+        process::exit(1);
+    }
+}
 
 fn main() {
-    let equation = env::args().nth(1).expect("No equation.");
-    let mut balancer = Balancer::new(&equation)
-        .unwrap_or_else(|parser_error| panic!("{}", parser_error.get_description()));
-    let balanced_equation = balancer
-        .balance_equation()
+    let options = Options::parse(env::args());
+
+    if let Some((first, second)) = &options.precipitation_reactants {
+        let precipitation = Precipitation::new(first, second)
+            .unwrap_or_else(|balancer_error| panic!("{}", balancer_error.get_description()));
+
+        println!("{}", precipitation.format());
+
+        return;
+    }
+
+    if let Some(path) = &options.file {
+        balance_file(&options, path);
+
+        return;
+    }
+
+    let argument = options.equation.as_deref().expect("No equation.");
+    let mut parser = Parser::new(argument);
+    let (equation, parser_errors) = parser.parse_equation_with_recovery();
+
+    if !parser_errors.is_empty() {
+        for parser_error in parser_errors {
+            eprintln!("{}", parser_error.get_diagnostic(argument));
+        }
+
+        process::exit(1);
+    }
+
+    for x in parser.get_warnings() {
+        eprintln!("Warning: {x}");
+    }
+
+    let balanced_equation = options.balance(equation)
         .unwrap_or_else(|balancer_error| panic!("{}", balancer_error.get_description()));
 
     println!("{balanced_equation}");
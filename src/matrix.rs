@@ -1,5 +1,7 @@
 use num::integer::gcd;
+use crate::matrix_operation::MatrixOperation;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Matrix {
     /// Cells
     pub cells: Vec<Vec<i32>>,
@@ -53,7 +55,44 @@ impl Matrix {
 
     /// Eliminates a matrix.
     pub fn eliminate(&mut self) {
+        self.eliminate_with_callback(|_, _| {});
+    }
+
+    /// Combines the target row with the source row, notifying the callback if the row changes.
+    fn combine_rows(
+        &mut self,
+        target_index: usize,
+        source_index: usize,
+        column_index: usize,
+        pivot: i32,
+        callback: &mut impl FnMut(MatrixOperation, &Matrix),
+    ) {
+        let gcd = gcd(pivot, self.cells[target_index][column_index]);
+        let target_factor = pivot / gcd;
+        let source_factor = -self.cells[target_index][column_index] / gcd;
+
+        self.cells[target_index] = Matrix::simplify_row(&Matrix::add_rows(
+            &self.cells[target_index].iter().map(|&x| target_factor * x).collect::<Vec<i32>>(),
+            &self.cells[source_index].iter().map(|&x| source_factor * x).collect::<Vec<i32>>()
+        ));
+
+        if source_factor != 0 {
+            callback(
+                MatrixOperation::CombineRows {
+                    target_index,
+                    source_index,
+                    target_factor,
+                    source_factor,
+                },
+                self,
+            );
+        }
+    }
+
+    /// Eliminates a matrix, passing every operation and the resulting matrix to the callback.
+    pub fn eliminate_with_callback(&mut self, mut callback: impl FnMut(MatrixOperation, &Matrix)) {
         self.cells = self.cells.iter().map(|x| Matrix::simplify_row(x)).collect();
+        callback(MatrixOperation::SimplifyRows, self);
 
         let mut pivots_count = 0;
 
@@ -72,16 +111,16 @@ impl Matrix {
 
             if pivot_row != pivots_count {
                 self.cells.swap(pivots_count, pivot_row);
+                callback(
+                    MatrixOperation::SwapRows { row_index_1: pivots_count, row_index_2: pivot_row },
+                    self,
+                );
             }
 
             pivots_count += 1;
 
             for j in pivots_count..self.rows_count {
-                let gcd = gcd(pivot, self.cells[j][i]);
-                self.cells[j] = Matrix::simplify_row(&Matrix::add_rows(
-                    &self.cells[j].iter().map(|&x| pivot / gcd * x).collect::<Vec<i32>>(),
-                    &self.cells[i].iter().map(|&x| -self.cells[j][i] / gcd * x).collect::<Vec<i32>>()
-                ));
+                self.combine_rows(j, i, i, pivot, &mut callback);
             }
         }
 
@@ -99,11 +138,7 @@ impl Matrix {
             let pivot = self.cells[i][pivot_column];
 
             for j in (0..i).rev() {
-                let gcd = gcd(pivot, self.cells[j][pivot_column]);
-                self.cells[j] = Matrix::simplify_row(&Matrix::add_rows(
-                    &self.cells[j].iter().map(|&x| pivot / gcd * x).collect::<Vec<i32>>(),
-                    &self.cells[i].iter().map(|&x| -self.cells[j][pivot_column] / gcd * x).collect::<Vec<i32>>()
-                ));
+                self.combine_rows(j, i, pivot_column, pivot, &mut callback);
             }
         }
     }
@@ -112,7 +147,7 @@ impl Matrix {
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
-    use crate::matrix::Matrix;
+    use crate::{matrix::Matrix, matrix_operation::MatrixOperation};
 
     /// Formats a matrix.
     pub fn format_matrix(matrix: &Matrix) -> String {
@@ -152,6 +187,47 @@ mod tests {
         assert_eq!(Matrix::simplify_row(&[0, -2, 2, 4]), [0, 1, -1, -2]);
     }
 
+    #[test]
+    fn test_eliminate_with_callback() {
+        let mut matrix = Matrix::new(4, 4);
+        matrix.cells = vec![
+            vec![2, 0, -2, 0],
+            vec![0, 2, -1, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+        ];
+        let mut operations = vec![];
+        matrix.eliminate_with_callback(|operation, _| operations.push(operation));
+        assert_eq!(operations, [MatrixOperation::SimplifyRows]);
+
+        matrix.cells[3] = vec![0, 0, 1, 1];
+        operations.clear();
+        matrix.eliminate_with_callback(|operation, _| operations.push(operation));
+        assert_eq!(
+            operations,
+            [
+                MatrixOperation::SimplifyRows,
+                MatrixOperation::SwapRows { row_index_1: 2, row_index_2: 3 },
+                MatrixOperation::CombineRows {
+                    target_index: 1,
+                    source_index: 2,
+                    target_factor: 1,
+                    source_factor: 1,
+                },
+                MatrixOperation::CombineRows {
+                    target_index: 0,
+                    source_index: 2,
+                    target_factor: 1,
+                    source_factor: 1,
+                },
+            ]
+        );
+        assert_eq!(
+            format_matrix(&matrix),
+            "[[1, 0, 0, 1],\n[0, 2, 0, 1],\n[0, 0, 1, 1],\n[0, 0, 0, 0]]"
+        );
+    }
+
     #[test]
     fn test_eliminate() {
         let mut matrix = Matrix::new(4, 4);
@@ -0,0 +1,58 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixOperation {
+    /// Every row is divided by the greatest common divisor of its cells.
+    SimplifyRows,
+    /// Two rows are swapped.
+    SwapRows { row_index_1: usize, row_index_2: usize },
+    /// The target row is replaced by the simplified sum of the multiplied target and source rows.
+    CombineRows {
+        target_index: usize,
+        source_index: usize,
+        target_factor: i32,
+        source_factor: i32,
+    },
+}
+
+impl MatrixOperation {
+    /// Returns the description, numbering the rows from 1.
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::SimplifyRows =>
+                "Divide every row by the greatest common divisor of its cells.".to_string(),
+            Self::SwapRows { row_index_1, row_index_2 } =>
+                format!("Swap rows {} and {}.", row_index_1 + 1, row_index_2 + 1),
+            Self::CombineRows { target_index, source_index, target_factor, source_factor } =>
+                format!(
+                    "R{} = {}\u{b7}R{} {} {}\u{b7}R{}, divided by the greatest common divisor.",
+                    target_index + 1,
+                    target_factor,
+                    target_index + 1,
+                    if *source_factor < 0 { "\u{2212}" } else { "+" },
+                    source_factor.abs(),
+                    source_index + 1,
+                ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix_operation::MatrixOperation;
+
+    #[test]
+    fn test_get_description() {
+        assert_eq!(
+            MatrixOperation::SwapRows { row_index_1: 0, row_index_2: 2 }.get_description(),
+            "Swap rows 1 and 3."
+        );
+        assert_eq!(
+            MatrixOperation::CombineRows {
+                target_index: 2,
+                source_index: 0,
+                target_factor: 2,
+                source_factor: -3,
+            }.get_description(),
+            "R3 = 2\u{b7}R3 \u{2212} 3\u{b7}R1, divided by the greatest common divisor."
+        );
+    }
+}
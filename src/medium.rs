@@ -0,0 +1,56 @@
+use crate::entity::Entity;
+
+/// Medium of a reaction in solution, which decides the ions balancing hydrogen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Medium {
+    /// Hydrogen is balanced with H+.
+    #[default]
+    Acidic,
+    /// Hydrogen is balanced with H+, which is then neutralized with OH−.
+    Basic,
+    /// Only water is consumed, forming H+ or OH−. The half-reaction method treats it as acidic.
+    Neutral,
+}
+
+impl Medium {
+    /// Returns the name.
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Acidic => "acidic",
+            Self::Basic => "basic",
+            Self::Neutral => "neutral",
+        }
+    }
+
+    /// Returns the sets of species that may complete an equation in the medium, from the
+    /// smallest.
+    pub fn get_completions(&self) -> Vec<Vec<Entity>> {
+        let completions: &[&[&str]] = match self {
+            Self::Acidic => &[&["H2O"], &["H{+}"], &["H2O", "H{+}"]],
+            Self::Basic => &[&["H2O"], &["OH{-}"], &["H2O", "OH{-}"]],
+            Self::Neutral => &[&["H2O"], &["H2O", "H{+}"], &["H2O", "OH{-}"]],
+        };
+
+        completions.iter()
+            .map(|x| x.iter().map(|y| y.parse().unwrap()).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::medium::Medium;
+
+    #[test]
+    fn test_get_name() {
+        assert_eq!(Medium::Basic.get_name(), "basic");
+    }
+
+    #[test]
+    fn test_get_completions() {
+        let completions = Medium::Basic.get_completions();
+        assert_eq!(completions.len(), 3);
+        assert_eq!(completions[1][0].to_string(), "OH{-}");
+        assert_eq!(completions[2].len(), 2);
+    }
+}
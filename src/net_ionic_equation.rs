@@ -0,0 +1,239 @@
+use itertools::Itertools;
+use crate::{
+    balanced_equation::BalancedEquation,
+    balancer::Balancer,
+    balancer_error::BalancerError,
+    dissociation::Dissociation,
+    electrolyte::Electrolyte,
+    element_data::ElementData,
+    entity::Entity,
+    equation::Equation,
+    phase::Phase,
+    sides::Sides,
+};
+
+/// Common gases, written as formulas in the ionic equations.
+const GASES: [&str; 13] =
+    ["H2", "O2", "N2", "F2", "Cl2", "CO", "CO2", "SO2", "H2S", "NH3", "NO", "NO2", "CH4"];
+
+/// The molecular, complete ionic and net ionic equations of a reaction in water.
+#[derive(Debug, Clone)]
+pub struct NetIonicEquation {
+    /// Balanced molecular equation
+    pub molecular_equation: BalancedEquation,
+    /// Complete ionic equation with the strong electrolytes written as their ions
+    pub complete_ionic_equation: BalancedEquation,
+    /// Ions that appear unchanged on both sides
+    pub spectator_ions: Vec<Entity>,
+    /// Balanced net ionic equation without the spectator ions
+    pub net_ionic_equation: BalancedEquation,
+}
+
+impl NetIonicEquation {
+    /// Balances the molecular equation, dissociates its strong electrolytes into aqueous ions
+    /// and the water of their hydrate parts, marks the phases of the other species and cancels
+    /// the spectator ions.
+    pub fn new(equation: &Equation) -> Result<Self, BalancerError> {
+        let molecular_equation = Balancer::from_equation(equation.clone()).balance()?;
+        let molecular_sides = Sides::from_balanced_equation(&molecular_equation);
+        let mut sides = Sides::default();
+        let water: Entity = "H2O(l)".parse().expect("Water is a valid formula.");
+
+        for (side, molecular_side) in [
+            (&mut sides.reactants, molecular_sides.reactants),
+            (&mut sides.products, molecular_sides.products),
+        ] {
+            for (entity, coefficient) in molecular_side {
+                match Dissociation::new(&entity)
+                    .filter(|_| Electrolyte::classify(&entity).is_dissociated()) {
+                    Some(dissociation) => {
+                        for (ion, count) in dissociation.get_ions() {
+                            let ion = ion.with_phase(Some(Phase::Aqueous));
+                            Sides::add_species(side, &ion, count * coefficient);
+                        }
+
+                        if dissociation.waters_count != 0 {
+                            let count = dissociation.waters_count * coefficient;
+                            Sides::add_species(side, &water, count);
+                        }
+                    },
+                    None => {
+                        let phase = entity.phase.or_else(|| Some(Self::get_phase(&entity)));
+                        Sides::add_species(side, &entity.with_phase(phase), coefficient);
+                    },
+                }
+            }
+        }
+
+        let complete_ionic_equation = sides.get_balanced_equation();
+
+        Self::check(&complete_ionic_equation)?;
+
+        let spectator_ions = sides.reactants.iter()
+            .filter(|(x, _)| x.charge != 0 && sides.products.iter().any(|(y, _)| y == x))
+            .map(|(x, _)| x.clone())
+            .collect();
+
+        sides.cancel();
+
+        if sides.reactants.is_empty() || sides.products.is_empty() {
+            return Err(BalancerError::SpeciesCancelOut);
+        }
+
+        sides.reduce();
+
+        let net_ionic_equation = sides.get_balanced_equation();
+
+        Self::check(&net_ionic_equation)?;
+
+        Ok(Self { molecular_equation, complete_ionic_equation, spectator_ions, net_ionic_equation })
+    }
+
+    /// Checks that the coefficients of the equation balance its atoms and charge.
+    fn check(balanced_equation: &BalancedEquation) -> Result<(), BalancerError> {
+        Balancer::from_equation(balanced_equation.equation.clone())
+            .check_answer(&balanced_equation.coefficients)
+    }
+
+    /// Returns the phase of the species that is written as a formula: water is a liquid, a
+    /// common gas is a gas, an insoluble compound or a metal is a solid, and the rest is
+    /// aqueous.
+    fn get_phase(entity: &Entity) -> Phase {
+        let composition = entity.get_composition();
+        let is_same = |formula: &str| formula.parse::<Entity>()
+            .expect("Gases are valid formulas.")
+            .get_composition() == composition;
+        let is_metal = match composition.elements.as_slice() {
+            [(symbol, _)] => ElementData::find(symbol).is_some_and(|x| x.is_metal),
+            _ => false,
+        };
+
+        if is_same("H2O") {
+            Phase::Liquid
+        } else if GASES.into_iter().any(is_same) {
+            Phase::Gas
+        } else if is_metal && composition.charge == 0
+            || Electrolyte::classify(entity) == Electrolyte::Insoluble {
+            Phase::Solid
+        } else {
+            Phase::Aqueous
+        }
+    }
+
+    /// Formats the equations and the spectator ions, one per line.
+    pub fn format(&self) -> String {
+        let spectator_ions = if self.spectator_ions.is_empty() {
+            "none".to_string()
+        } else {
+            self.spectator_ions.iter().map(Entity::format).join(", ")
+        };
+
+        [
+            format!("Molecular equation: {}", self.molecular_equation.format()),
+            format!("Complete ionic equation: {}", self.complete_ionic_equation.format()),
+            format!("Spectator ions: {spectator_ions}"),
+            format!("Net ionic equation: {}", self.net_ionic_equation.format()),
+        ].join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        balancer_error::BalancerError,
+        entity::Entity,
+        net_ionic_equation::NetIonicEquation,
+        parser::Parser,
+        phase::Phase,
+    };
+
+    fn get_net_ionic_equation(equation: &str) -> Result<NetIonicEquation, BalancerError> {
+        NetIonicEquation::new(&Parser::new(equation).parse_equation().unwrap())
+    }
+
+    #[test]
+    fn test_new() {
+        let net_ionic_equation = get_net_ionic_equation("AgNO3 + NaCl = AgCl + NaNO3").unwrap();
+        assert_eq!(
+            net_ionic_equation.complete_ionic_equation.format(),
+            "Ag{+}(aq) + NO3{\u{2212}}(aq) + Na{+}(aq) + Cl{\u{2212}}(aq) = AgCl(s) + Na{+}(aq) \
+                + NO3{\u{2212}}(aq)"
+        );
+        assert_eq!(net_ionic_equation.spectator_ions.len(), 2);
+        assert_eq!(
+            net_ionic_equation.net_ionic_equation.format(),
+            "Ag{+}(aq) + Cl{\u{2212}}(aq) = AgCl(s)"
+        );
+
+        let net_ionic_equation =
+            get_net_ionic_equation("Pb(NO3)2(aq) + KI(aq) = PbI2(s) + KNO3(aq)").unwrap();
+        assert_eq!(
+            net_ionic_equation.net_ionic_equation.format(),
+            "Pb{2+}(aq) + 2\u{a0}I{\u{2212}}(aq) = PbI2(s)"
+        );
+
+        let net_ionic_equation =
+            get_net_ionic_equation("KMnO4 + HCl = KCl + MnCl2 + H2O + Cl2").unwrap();
+        assert_eq!(
+            net_ionic_equation.spectator_ions.iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            ["K{+}(aq)", "Cl{-}(aq)"]
+        );
+        assert_eq!(
+            net_ionic_equation.net_ionic_equation.format(),
+            "2\u{a0}MnO4{\u{2212}}(aq) + 16\u{a0}H{+}(aq) + 10\u{a0}Cl{\u{2212}}(aq) \
+                = 2\u{a0}Mn{2+}(aq) + 8\u{a0}H2O(l) + 5\u{a0}Cl2(g)"
+        );
+
+        let net_ionic_equation =
+            get_net_ionic_equation("CuSO4*5H2O + NaOH = Cu(OH)2 + Na2SO4 + H2O").unwrap();
+        assert!(net_ionic_equation.complete_ionic_equation.format()
+            .starts_with("Cu{2+}(aq) + SO4{2\u{2212}}(aq) + 5\u{a0}H2O(l)"));
+        assert_eq!(
+            net_ionic_equation.net_ionic_equation.format(),
+            "Cu{2+}(aq) + 2\u{a0}OH{\u{2212}}(aq) = Cu(OH)2(s)"
+        );
+
+        let net_ionic_equation = get_net_ionic_equation("H2SO4 + NaOH = Na2SO4 + H2O").unwrap();
+        assert_eq!(
+            net_ionic_equation.net_ionic_equation.format(),
+            "H{+}(aq) + OH{\u{2212}}(aq) = H2O(l)"
+        );
+
+        let net_ionic_equation =
+            get_net_ionic_equation("CH3COOH + KOH = CH3COOK + H2O").unwrap();
+        assert_eq!(
+            net_ionic_equation.net_ionic_equation.format(),
+            "CH3COOH(aq) + OH{\u{2212}}(aq) = CH3COO{\u{2212}}(aq) + H2O(l)"
+        );
+
+        assert!(matches!(
+            get_net_ionic_equation("NaCl + KNO3 = NaNO3 + KCl"),
+            Err(BalancerError::SpeciesCancelOut)
+        ));
+    }
+
+    #[test]
+    fn test_get_phase() {
+        let get_phase =
+            |formula: &str| NetIonicEquation::get_phase(&formula.parse::<Entity>().unwrap());
+        assert_eq!(get_phase("H2O"), Phase::Liquid);
+        assert_eq!(get_phase("CO2"), Phase::Gas);
+        assert_eq!(get_phase("AgCl"), Phase::Solid);
+        assert_eq!(get_phase("Zn"), Phase::Solid);
+        assert_eq!(get_phase("Zn{2+}"), Phase::Aqueous);
+        assert_eq!(get_phase("C6H12O6"), Phase::Aqueous);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            get_net_ionic_equation("BaCl2 + Na2SO4 = BaSO4 + NaCl").unwrap().format(),
+            "Molecular equation: BaCl2 + Na2SO4 = BaSO4 + 2\u{a0}NaCl\n\
+                Complete ionic equation: Ba{2+}(aq) + 2\u{a0}Cl{\u{2212}}(aq) \
+                + 2\u{a0}Na{+}(aq) + SO4{2\u{2212}}(aq) = BaSO4(s) + 2\u{a0}Na{+}(aq) \
+                + 2\u{a0}Cl{\u{2212}}(aq)\n\
+                Spectator ions: Cl{\u{2212}}(aq), Na{+}(aq)\n\
+                Net ionic equation: Ba{2+}(aq) + SO4{2\u{2212}}(aq) = BaSO4(s)"
+        );
+    }
+}
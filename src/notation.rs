@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// The formulas are written as they are entered.
+    #[default]
+    AsWritten,
+    /// The formulas are written in Hill order: carbon, hydrogen, then the other elements
+    /// alphabetically, or all the elements alphabetically if there is no carbon.
+    Hill,
+}
@@ -0,0 +1,100 @@
+use num::{rational::Ratio, Signed};
+use crate::{entity::Entity, oxidation_state::OxidationState};
+
+/// A change of the oxidation state of the atoms of an element from a reactant to a product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OxidationChange {
+    /// Name of the element
+    pub element_name: String,
+    /// State in the reactant
+    pub initial_state: Ratio<i32>,
+    /// State in the product
+    pub final_state: Ratio<i32>,
+    /// Number of atoms in the balanced equation that change the state
+    pub atoms_count: i32,
+    /// Reactant
+    pub reactant: Entity,
+    /// Product
+    pub product: Entity,
+}
+
+impl OxidationChange {
+    /// OxidationChange constructor.
+    pub fn new(
+        element_name: String,
+        initial_state: Ratio<i32>,
+        final_state: Ratio<i32>,
+        atoms_count: i32,
+        reactant: Entity,
+        product: Entity,
+    ) -> Self {
+        Self { element_name, initial_state, final_state, atoms_count, reactant, product }
+    }
+
+    /// Checks whether the atoms are oxidized, i.e. their state increases.
+    pub fn is_oxidation(&self) -> bool {
+        self.final_state > self.initial_state
+    }
+
+    /// Returns the number of electrons lost by all the atoms, or gained if it is a reduction.
+    pub fn get_electrons_count(&self) -> Ratio<i32> {
+        (self.final_state - self.initial_state).abs() * self.atoms_count
+    }
+
+    /// Returns the description.
+    pub fn get_description(&self) -> String {
+        format!(
+            "{} is {} from {} in {} to {} in {}: {} {} {} {} electrons.",
+            self.element_name,
+            if self.is_oxidation() { "oxidized" } else { "reduced" },
+            OxidationState::format_value(self.initial_state),
+            self.reactant.format(),
+            OxidationState::format_value(self.final_state),
+            self.product.format(),
+            self.atoms_count,
+            if self.atoms_count == 1 { "atom" } else { "atoms" },
+            match (self.is_oxidation(), self.atoms_count == 1) {
+                (true, true) => "loses",
+                (true, false) => "lose",
+                (false, true) => "gains",
+                (false, false) => "gain",
+            },
+            self.get_electrons_count(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::{entity::Entity, oxidation_change::OxidationChange};
+
+    fn get_change() -> OxidationChange {
+        OxidationChange::new(
+            "Mn".to_string(),
+            Ratio::from_integer(7),
+            Ratio::from_integer(2),
+            24,
+            "KMnO4".parse::<Entity>().unwrap(),
+            "MnSO4".parse::<Entity>().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_is_oxidation() {
+        assert!(!get_change().is_oxidation());
+    }
+
+    #[test]
+    fn test_get_electrons_count() {
+        assert_eq!(get_change().get_electrons_count(), Ratio::from_integer(120));
+    }
+
+    #[test]
+    fn test_get_description() {
+        assert_eq!(
+            get_change().get_description(),
+            "Mn is reduced from +7 in KMnO4 to +2 in MnSO4: 24 atoms gain 120 electrons."
+        );
+    }
+}
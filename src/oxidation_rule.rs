@@ -0,0 +1,51 @@
+/// The rule that decides an oxidation state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OxidationRule {
+    /// The species consists of one element, so the state is the charge per atom.
+    SingleElement,
+    /// Fluorine is always −1.
+    Fluorine,
+    /// Alkali metals are +1.
+    AlkaliMetal,
+    /// Alkaline earth metals are +2.
+    AlkalineEarthMetal,
+    /// Hydrogen is +1.
+    Hydrogen,
+    /// Hydrogen is −1 with only less electronegative elements.
+    Hydride,
+    /// Oxygen is −2.
+    Oxygen,
+    /// Oxygen is −1 when the rest of the species leaves it that charge.
+    Peroxide,
+    /// Oxygen is −1/2 when the rest of the species leaves it that charge.
+    Superoxide,
+    /// The most electronegative element of the species takes the charge of its group:
+    /// −1 for halogens, −2 for chalcogens, −3 for pnictogens and −4 for the carbon group.
+    MostElectronegative,
+    /// The element is the only unknown one in the polyatomic ion.
+    PolyatomicIon { ion: String },
+    /// The element is the only unknown one, so the sum of the states equals the charge.
+    Remainder,
+}
+
+impl OxidationRule {
+    /// Returns the description.
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::SingleElement => "the charge per atom of a single element".to_string(),
+            Self::Fluorine => "fluorine is always \u{2212}1".to_string(),
+            Self::AlkaliMetal => "alkali metals are +1".to_string(),
+            Self::AlkalineEarthMetal => "alkaline earth metals are +2".to_string(),
+            Self::Hydrogen => "hydrogen is +1".to_string(),
+            Self::Hydride => "hydrogen is \u{2212}1 in hydrides".to_string(),
+            Self::Oxygen => "oxygen is \u{2212}2".to_string(),
+            Self::Peroxide => "oxygen is \u{2212}1 in peroxides".to_string(),
+            Self::Superoxide => "oxygen is \u{2212}1/2 in superoxides".to_string(),
+            Self::MostElectronegative =>
+                "the most electronegative element takes the charge of its group".to_string(),
+            Self::PolyatomicIon { ion } =>
+                format!("the sum of the states equals the charge of {ion}"),
+            Self::Remainder => "the sum of the states equals the charge".to_string(),
+        }
+    }
+}
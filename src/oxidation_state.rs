@@ -0,0 +1,68 @@
+use num::{rational::Ratio, Zero};
+use crate::oxidation_rule::OxidationRule;
+
+/// The oxidation state of the atoms of an element in a species, which is the average
+/// for mixed states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OxidationState {
+    /// Name of the element
+    pub element_name: String,
+    /// Number of atoms
+    pub count: i32,
+    /// Oxidation state
+    pub value: Ratio<i32>,
+    /// Rule that decided the state
+    pub rule: OxidationRule,
+}
+
+impl OxidationState {
+    /// OxidationState constructor.
+    pub fn new(element_name: String, count: i32, value: Ratio<i32>, rule: OxidationRule) -> Self {
+        Self { element_name, count, value, rule }
+    }
+
+    /// Formats a state with its sign, such as +8/3, −2 or 0.
+    pub fn format_value(value: Ratio<i32>) -> String {
+        if value > Ratio::zero() {
+            format!("+{value}")
+        } else if value < Ratio::zero() {
+            format!("\u{2212}{}", -value)
+        } else {
+            "0".to_string()
+        }
+    }
+
+    /// Formats the state with the rule that decided it.
+    pub fn format(&self) -> String {
+        format!(
+            "{} {} ({})",
+            self.element_name,
+            Self::format_value(self.value),
+            self.rule.get_description(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::{oxidation_rule::OxidationRule, oxidation_state::OxidationState};
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(OxidationState::format_value(Ratio::new(8, 3)), "+8/3");
+        assert_eq!(OxidationState::format_value(Ratio::from_integer(-2)), "\u{2212}2");
+        assert_eq!(OxidationState::format_value(Ratio::from_integer(0)), "0");
+    }
+
+    #[test]
+    fn test_format() {
+        let state = OxidationState::new(
+            "O".to_string(),
+            4,
+            Ratio::from_integer(-2),
+            OxidationRule::Oxygen,
+        );
+        assert_eq!(state.format(), "O \u{2212}2 (oxygen is \u{2212}2)");
+    }
+}
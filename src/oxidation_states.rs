@@ -0,0 +1,352 @@
+use itertools::Itertools;
+use num::rational::Ratio;
+use crate::{
+    composition::Composition,
+    element_data::ElementData,
+    entity::Entity,
+    oxidation_rule::OxidationRule,
+    oxidation_state::OxidationState,
+};
+
+/// Polyatomic ions whose central atom is decided by the charge of the ion when the rest of
+/// the species does not decide it.
+const POLYATOMIC_IONS: [&str; 15] = [
+    "C2O4{2-}", "S2O3{2-}", "SO4{2-}", "SO3{2-}", "NO3{-}", "NO2{-}", "PO4{3-}", "CO3{2-}",
+    "ClO4{-}", "ClO3{-}", "ClO2{-}", "ClO{-}", "BrO3{-}", "IO3{-}", "CN{-}",
+];
+
+/// The oxidation states of the elements of a species.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OxidationStates {
+    /// States of the elements, in order of their first appearance
+    pub states: Vec<OxidationState>,
+    /// Names of the elements whose states the rules cannot decide
+    pub undetermined_elements_names: Vec<String>,
+}
+
+impl OxidationStates {
+    /// Assigns the oxidation states to the elements of the species.
+    pub fn new(entity: &Entity) -> Self {
+        let composition = entity.get_composition();
+        let mut values: Vec<Option<(Ratio<i32>, OxidationRule)>> =
+            vec![None; composition.elements.len()];
+
+        if composition.elements.len() == 1 {
+            values[0] = Some((
+                Ratio::new(composition.charge, composition.elements[0].1),
+                OxidationRule::SingleElement,
+            ));
+        } else {
+            Self::assign_fixed_states(&composition, &mut values);
+
+            while values.iter().any(Option::is_none) {
+                let is_assigned = Self::assign_remainder(&composition, &mut values)
+                    || Self::assign_most_electronegative(&composition, &mut values)
+                    || Self::assign_polyatomic_ion(&composition, &mut values);
+
+                if !is_assigned {
+                    break;
+                }
+            }
+        }
+
+        let mut states = vec![];
+        let mut undetermined_elements_names = vec![];
+
+        for ((name, count), value) in composition.elements.iter().zip(values) {
+            match value {
+                Some((value, rule)) =>
+                    states.push(OxidationState::new(name.clone(), *count, value, rule)),
+                None => undetermined_elements_names.push(name.clone()),
+            }
+        }
+
+        Self { states, undetermined_elements_names }
+    }
+
+    /// Assigns the states of fluorine, the alkali and alkaline earth metals, hydrogen and
+    /// oxygen.
+    fn assign_fixed_states(
+        composition: &Composition,
+        values: &mut [Option<(Ratio<i32>, OxidationRule)>],
+    ) {
+        let hydrogen_electronegativity = ElementData::find("H").and_then(|x| x.electronegativity);
+        let is_hydride = composition.elements.iter()
+            .filter(|(name, _)| name != "H")
+            .all(|(name, _)| {
+                let electronegativity = ElementData::find(name).and_then(|x| x.electronegativity);
+
+                match (electronegativity, hydrogen_electronegativity) {
+                    (Some(x), Some(y)) => x < y - 0.1,
+                    _ => true,
+                }
+            });
+
+        for (i, (name, _)) in composition.elements.iter().enumerate() {
+            let element_data = ElementData::find(name);
+
+            values[i] = match name.as_str() {
+                "F" => Some((Ratio::from_integer(-1), OxidationRule::Fluorine)),
+                "H" if is_hydride => Some((Ratio::from_integer(-1), OxidationRule::Hydride)),
+                "H" => Some((Ratio::from_integer(1), OxidationRule::Hydrogen)),
+                _ if element_data.is_some_and(ElementData::is_alkali_metal) =>
+                    Some((Ratio::from_integer(1), OxidationRule::AlkaliMetal)),
+                _ if element_data.is_some_and(ElementData::is_alkaline_earth_metal) =>
+                    Some((Ratio::from_integer(2), OxidationRule::AlkalineEarthMetal)),
+                _ => None,
+            };
+        }
+
+        let Some(i) = composition.elements.iter().position(|(name, _)| name == "O") else {
+            return;
+        };
+
+        values[i] = if values.iter().filter(|x| x.is_none()).count() == 1 {
+            let value = Self::get_remainder(composition, values, i);
+            let rule = match (*value.numer(), *value.denom()) {
+                (-2, 1) => OxidationRule::Oxygen,
+                (-1, 1) => OxidationRule::Peroxide,
+                (-1, 2) => OxidationRule::Superoxide,
+                _ => OxidationRule::Remainder,
+            };
+
+            Some((value, rule))
+        } else {
+            Some((Ratio::from_integer(-2), OxidationRule::Oxygen))
+        };
+    }
+
+    /// Returns the state of the element by its index that makes the sum of the states equal
+    /// the charge, assuming it is the only unknown one.
+    fn get_remainder(
+        composition: &Composition,
+        values: &[Option<(Ratio<i32>, OxidationRule)>],
+        element_index: usize,
+    ) -> Ratio<i32> {
+        let known_sum: Ratio<i32> = composition.elements.iter().zip(values)
+            .filter_map(|((_, count), value)| value.as_ref().map(|(x, _)| x * count))
+            .sum();
+
+        (Ratio::from_integer(composition.charge) - known_sum)
+            / composition.elements[element_index].1
+    }
+
+    /// Assigns the state of the only unknown element, so that the sum of the states equals
+    /// the charge.
+    fn assign_remainder(
+        composition: &Composition,
+        values: &mut [Option<(Ratio<i32>, OxidationRule)>],
+    ) -> bool {
+        let unknowns: Vec<usize> = (0..values.len()).filter(|&i| values[i].is_none()).collect();
+
+        if unknowns.len() != 1 {
+            return false;
+        }
+
+        let value = Self::get_remainder(composition, values, unknowns[0]);
+        values[unknowns[0]] = Some((value, OxidationRule::Remainder));
+
+        true
+    }
+
+    /// Assigns the charge of its group to the most electronegative element, if it is an
+    /// unknown nonmetal.
+    fn assign_most_electronegative(
+        composition: &Composition,
+        values: &mut [Option<(Ratio<i32>, OxidationRule)>],
+    ) -> bool {
+        let mut index = None;
+        let mut max_electronegativity = 0.0;
+
+        for (i, (name, _)) in composition.elements.iter().enumerate() {
+            let Some(electronegativity) = ElementData::find(name).and_then(|x| x.electronegativity)
+            else {
+                return false;
+            };
+
+            if electronegativity > max_electronegativity {
+                index = Some(i);
+                max_electronegativity = electronegativity;
+            }
+        }
+
+        let Some(i) = index.filter(|&i| values[i].is_none()) else {
+            return false;
+        };
+
+        match ElementData::find(&composition.elements[i].0) {
+            Some(x) if !x.is_metal && (14..=17).contains(&x.group) => {
+                let value = Ratio::from_integer(i32::from(x.group) - 18);
+                values[i] = Some((value, OxidationRule::MostElectronegative));
+
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Assigns the state of the only unknown element of a polyatomic ion in the species,
+    /// if the element is not found outside the ion.
+    fn assign_polyatomic_ion(
+        composition: &Composition,
+        values: &mut [Option<(Ratio<i32>, OxidationRule)>],
+    ) -> bool {
+        for ion in POLYATOMIC_IONS {
+            let ion_composition = ion.parse::<Entity>()
+                .expect("Polyatomic ions are valid formulas.")
+                .get_composition();
+            let ions_count = ion_composition.elements.iter()
+                .map(|(name, count)| composition.count_element_by_name(name) / count)
+                .min()
+                .unwrap_or(0);
+
+            if ions_count == 0 {
+                continue;
+            }
+
+            let indices: Vec<usize> = ion_composition.elements.iter()
+                .filter_map(|(name, _)| composition.elements.iter().position(|(x, _)| x == name))
+                .collect();
+            let unknowns: Vec<usize> = indices.iter()
+                .copied()
+                .filter(|&i| values[i].is_none())
+                .collect();
+
+            if unknowns.len() != 1 {
+                continue;
+            }
+
+            let (name, count) = &composition.elements[unknowns[0]];
+            let count_in_ion = ion_composition.count_element_by_name(name);
+
+            if *count != ions_count * count_in_ion {
+                continue;
+            }
+
+            let known_sum: Ratio<i32> = indices.iter()
+                .filter_map(|&i| values[i].as_ref().map(|(x, _)| {
+                    x * ion_composition.count_element_by_name(&composition.elements[i].0)
+                }))
+                .sum();
+            let value = (Ratio::from_integer(ion_composition.charge) - known_sum) / count_in_ion;
+            let ion = ion.parse::<Entity>().expect("Polyatomic ions are valid formulas.").format();
+
+            values[unknowns[0]] = Some((value, OxidationRule::PolyatomicIon { ion }));
+
+            return true;
+        }
+
+        false
+    }
+
+    /// Returns the state of the element by its name.
+    pub fn get_state(&self, element_name: &str) -> Option<Ratio<i32>> {
+        self.states.iter().find(|x| x.element_name == element_name).map(|x| x.value)
+    }
+
+    /// Returns the state of every atom, in order of the elements.
+    pub fn get_atoms_states(&self) -> Vec<(String, Ratio<i32>)> {
+        self.states.iter()
+            .flat_map(|x| (0..x.count).map(|_| (x.element_name.clone(), x.value)))
+            .collect()
+    }
+
+    /// Checks whether the states of all the elements are decided.
+    pub fn is_complete(&self) -> bool {
+        self.undetermined_elements_names.is_empty()
+    }
+
+    /// Formats the states with the rules that decided them.
+    pub fn format(&self) -> String {
+        self.states.iter()
+            .map(OxidationState::format)
+            .chain(self.undetermined_elements_names.iter().map(|x| format!("{x} undetermined")))
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::{entity::Entity, oxidation_rule::OxidationRule, oxidation_states::OxidationStates};
+
+    fn get_states(formula: &str) -> OxidationStates {
+        OxidationStates::new(&formula.parse::<Entity>().unwrap())
+    }
+
+    fn get_values(formula: &str) -> Vec<(String, Ratio<i32>)> {
+        get_states(formula).states.into_iter().map(|x| (x.element_name, x.value)).collect()
+    }
+
+    fn get_rules(formula: &str) -> Vec<OxidationRule> {
+        get_states(formula).states.into_iter().map(|x| x.rule).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            get_values("Cr2O7{2-}"),
+            [("Cr".to_string(), Ratio::from_integer(6)), ("O".to_string(), Ratio::from_integer(-2))]
+        );
+        assert_eq!(get_states("Fe3O4").get_state("Fe"), Some(Ratio::new(8, 3)));
+        assert_eq!(get_states("O2").get_state("O"), Some(Ratio::from_integer(0)));
+        assert_eq!(get_states("Hg2{2+}").get_state("Hg"), Some(Ratio::from_integer(1)));
+        assert_eq!(get_states("OF2").get_state("O"), Some(Ratio::from_integer(2)));
+        assert_eq!(get_states("CH3COOH").get_state("C"), Some(Ratio::from_integer(0)));
+        assert!(get_states("e").states.is_empty());
+    }
+
+    #[test]
+    fn test_new_with_exceptions() {
+        assert_eq!(get_rules("H2O2"), [OxidationRule::Hydrogen, OxidationRule::Peroxide]);
+        assert_eq!(get_rules("KO2"), [OxidationRule::AlkaliMetal, OxidationRule::Superoxide]);
+        assert_eq!(get_states("KO2").get_state("O"), Some(Ratio::new(-1, 2)));
+        assert_eq!(get_rules("CaH2"), [OxidationRule::AlkalineEarthMetal, OxidationRule::Hydride]);
+        assert_eq!(get_states("NaBH4").get_state("B"), Some(Ratio::from_integer(3)));
+        assert_eq!(get_states("PH3").get_state("P"), Some(Ratio::from_integer(-3)));
+    }
+
+    #[test]
+    fn test_new_with_several_unknown_elements() {
+        assert_eq!(get_states("FeCl3").get_state("Fe"), Some(Ratio::from_integer(3)));
+        assert_eq!(get_states("NH4Cl").get_state("N"), Some(Ratio::from_integer(-3)));
+
+        let states = get_states("FeSO4");
+        assert_eq!(states.get_state("Fe"), Some(Ratio::from_integer(2)));
+        assert_eq!(states.get_state("S"), Some(Ratio::from_integer(6)));
+        assert_eq!(
+            states.states[1].rule,
+            OxidationRule::PolyatomicIon { ion: "SO4{2\u{2212}}".to_string() }
+        );
+
+        let states = get_states("K4[Fe(CN)6]");
+        assert_eq!(states.get_state("Fe"), Some(Ratio::from_integer(2)));
+        assert_eq!(states.get_state("C"), Some(Ratio::from_integer(2)));
+        assert_eq!(get_states("(NH4)2SO4").get_state("N"), Some(Ratio::from_integer(-3)));
+
+        let states = get_states("CuFeS2");
+        assert!(!states.is_complete());
+        assert_eq!(states.undetermined_elements_names, ["Cu", "Fe"]);
+    }
+
+    #[test]
+    fn test_get_atoms_states() {
+        assert_eq!(get_states("Fe3O4").get_atoms_states().len(), 7);
+        assert_eq!(
+            get_states("H2O").get_atoms_states(),
+            [
+                ("H".to_string(), Ratio::from_integer(1)),
+                ("H".to_string(), Ratio::from_integer(1)),
+                ("O".to_string(), Ratio::from_integer(-2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            get_states("Fe3O4").format(),
+            "Fe +8/3 (the sum of the states equals the charge), O \u{2212}2 (oxygen is \u{2212}2)"
+        );
+    }
+}
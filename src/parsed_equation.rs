@@ -0,0 +1,56 @@
+use crate::{equation::Equation, parser_error::ParserError};
+
+pub struct ParsedEquation {
+    /// Source text of the equation
+    pub source: String,
+    /// Line number, starting from 1
+    pub line: usize,
+    /// Column number, starting from 1
+    pub column: usize,
+    /// Byte offset of the equation in the input
+    pub offset: usize,
+    /// Parsed equation or parser error
+    pub result: Result<Equation, ParserError>,
+    /// Warnings about the source text
+    pub warnings: Vec<String>,
+}
+
+impl ParsedEquation {
+    /// ParsedEquation constructor.
+    pub fn new(
+        source: String,
+        line: usize,
+        column: usize,
+        offset: usize,
+        result: Result<Equation, ParserError>,
+    ) -> Self {
+        Self { source, line, column, offset, result, warnings: vec![] }
+    }
+
+    /// Returns the line and column of the parser error in the input.
+    pub fn get_error_position(&self) -> Option<(usize, usize)> {
+        let start_index = self.result.as_ref().err()?.get_span().start;
+        let column_offset = self.source[..start_index.min(self.source.len())].chars().count();
+
+        Some((self.line, self.column + column_offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::Parser;
+
+    #[test]
+    fn test_get_error_position() {
+        let parsed_equations = Parser::parse_equations("H2 + O2 = H2O\n  Fe + = Fe2O3");
+        assert_eq!(parsed_equations[0].get_error_position(), None);
+        assert_eq!(parsed_equations[1].get_error_position(), Some((2, 8)));
+    }
+
+    #[test]
+    fn test_warnings() {
+        let parsed_equations = Parser::parse_equations("H2 + O2 = H2O; 2 H2 + O2 = H2O");
+        assert!(parsed_equations[0].warnings.is_empty());
+        assert_eq!(parsed_equations[1].warnings.len(), 1);
+    }
+}
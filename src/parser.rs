@@ -1,78 +1,216 @@
 use std::convert::TryFrom;
 use crate::{
+    bracket::Bracket,
+    charge_style::ChargeStyle,
     element::Element,
     entity::Entity,
     equation::Equation,
     group::Group,
+    hydrate::Hydrate,
     item::Item,
+    lexer::Lexer,
+    parsed_equation::ParsedEquation,
     parser_error::ParserError,
-    regular_expression::RegularExpression,
+    phase::Phase,
+    span::Span,
+    token::Token,
+    token_kind::TokenKind,
 };
 
 pub struct Parser<'eq> {
     equation: &'eq str,
-    position: usize,
+    tokens: Vec<Token<'eq>>,
+    index: usize,
+    ignored_coefficients: Vec<Span>,
 }
 
-impl Parser<'_> {
+impl<'eq> Parser<'eq> {
     /// Parser constructor.
-    pub fn new(equation: &str) -> Parser {
-        Parser { equation, position: 0 }
+    pub fn new(equation: &'eq str) -> Self {
+        Self {
+            equation,
+            tokens: Lexer::tokenize(equation),
+            index: 0,
+            ignored_coefficients: vec![],
+        }
+    }
+
+    /// Returns the spans of the coefficients that are skipped in the input, since the
+    /// balancer calculates its own.
+    pub fn get_ignored_coefficients(&self) -> &[Span] {
+        &self.ignored_coefficients
+    }
+
+    /// Returns the warnings about the input: the skipped coefficients.
+    pub fn get_warnings(&self) -> Vec<String> {
+        self.ignored_coefficients.iter()
+            .map(|x| format!(
+                "The coefficient {} is ignored: the balancer calculates the coefficients.",
+                &self.equation[x.start..x.end],
+            ))
+            .collect()
+    }
+
+    /// Parses the equations separated by newlines or semicolons.
+    ///
+    /// Blank lines and comments starting with `#` are skipped.
+    pub fn parse_equations(input: &str) -> Vec<ParsedEquation> {
+        let mut parsed_equations = vec![];
+        let mut line_offset = 0;
+
+        for (line_index, line) in input.split('\n').enumerate() {
+            let code = line.split('#').next().unwrap_or_default();
+            let mut segment_offset = 0;
+
+            for segment in code.split(';') {
+                let source = segment.trim();
+
+                if !source.is_empty() {
+                    let offset = segment_offset + segment.find(source).unwrap_or_default();
+
+                    let mut parser = Parser::new(source);
+                    let result = parser.parse_equation();
+                    let mut parsed_equation = ParsedEquation::new(
+                        source.to_string(),
+                        line_index + 1,
+                        line[..offset].chars().count() + 1,
+                        line_offset + offset,
+                        result,
+                    );
+                    parsed_equation.warnings = parser.get_warnings();
+                    parsed_equations.push(parsed_equation);
+                }
+
+                segment_offset += segment.len() + 1;
+            }
+
+            line_offset += line.len() + 1;
+        }
+
+        parsed_equations
+    }
+
+    /// Returns the start of the next token or the end of the equation.
+    fn get_position(&self) -> usize {
+        self.tokens.get(self.index).map_or(self.equation.len(), |x| x.span.start)
     }
 
-    /// Returns a substring.
-    fn get_substring(&self) -> &str {
-        &self.equation[self.position..]
+    /// Returns the end of the last taken token.
+    fn get_last_token_end(&self) -> usize {
+        self.index.checked_sub(1).map_or(0, |x| self.tokens[x].span.end)
     }
 
     /// Returns the next token.
-    fn get_next_token(&self) -> Result<Option<String>, ParserError> {
-        if self.position == self.equation.chars().count() {
-            return Ok(None);
+    fn get_next_token(&self) -> Result<Option<Token<'eq>>, ParserError> {
+        match self.tokens.get(self.index) {
+            Some(x) if x.kind == TokenKind::Invalid => Err(ParserError::InvalidSymbol {
+                span: x.span,
+                found: Some(x.text.to_string()),
+            }),
+            x => Ok(x.copied()),
         }
+    }
+
+    /// Returns the kind of the next token.
+    fn get_next_token_kind(&self) -> Result<Option<TokenKind>, ParserError> {
+        Ok(self.get_next_token()?.map(|x| x.kind))
+    }
 
-        match RegularExpression::Token.get_regex().captures(self.get_substring()) {
-            Some(x) => Ok(Some(x[0].to_string())),
-            None => Err(ParserError::InvalidSymbol { start_index: self.position }),
+    /// Returns the span and the text of the next token for an error.
+    fn get_next_token_for_error(&self) -> (Span, Option<String>) {
+        match self.tokens.get(self.index) {
+            Some(x) => (x.span, Some(x.text.to_string())),
+            None => (Span::new(self.equation.len(), self.equation.len()), None),
         }
     }
 
+    /// Returns the span from the start position to the end of the last taken token.
+    fn get_span_from(&self, start_position: usize) -> Span {
+        Span::new(start_position, self.get_last_token_end().max(start_position))
+    }
+
     /// Takes a token.
-    fn take_token(&mut self) -> Result<String, ParserError> {
-        let next_token = self.get_next_token()?.ok_or(ParserError::AdvancingBeyondLastToken)?;
-        self.position += next_token.chars().count();
-        self.skip_spaces();
+    fn take_token(&mut self) -> Result<Token<'eq>, ParserError> {
+        let next_token = self.get_next_token()?.ok_or(ParserError::AdvancingBeyondLastToken {
+            span: Span::new(self.equation.len(), self.equation.len()),
+            found: None,
+        })?;
+        self.index += 1;
 
         Ok(next_token)
     }
 
-    /// Consumes a string.
-    fn consume(&mut self, string: &str) -> Result<(), ParserError> {
-        if self.take_token()? == string {
+    /// Consumes a token of the kind.
+    fn consume(&mut self, kind: TokenKind) -> Result<(), ParserError> {
+        let token = self.take_token()?;
+
+        if token.kind == kind {
             Ok(())
         } else {
-            Err(ParserError::TokenDoesNotMatchString)
+            Err(ParserError::TokenDoesNotMatchString {
+                span: token.span,
+                found: Some(token.text.to_string()),
+                expected: kind.get_text().unwrap_or_default().to_string(),
+            })
         }
     }
 
-    /// Skips the spaces.
-    fn skip_spaces(&mut self) {
-        if let Some(x) = RegularExpression::Spaces.get_regex().captures(self.get_substring()) {
-            self.position += x[0].chars().count();
+    /// Parses an optional number.
+    fn parse_optional_number(&mut self) -> Result<u8, ParserError> {
+        if self.get_next_token_kind()? != Some(TokenKind::Number) {
+            return Ok(1);
         }
+
+        let token = self.take_token()?;
+
+        token.text.parse::<u8>().map_err(|_e| ParserError::NumberIsTooLarge {
+            span: token.span,
+            found: Some(token.text.to_string()),
+        })
     }
 
-    /// Parses an optional number.
-    fn parse_optional_number(&mut self) -> Result<u8, ParserError> {
-        match self.get_next_token()? {
-            Some(x) => {
-                if RegularExpression::Digits.get_regex().is_match(&x) {
-                    self.take_token()?.parse::<u8>().map_err(|_e| ParserError::NumberIsTooLarge)
-                } else {
-                    Ok(1)
-                }
+    /// Parses a charge: an optional number followed by the sign.
+    fn parse_charge(&mut self) -> Result<i8, ParserError> {
+        let (span, found) = self.get_next_token_for_error();
+        let charge_without_sign = i8::try_from(self.parse_optional_number()?)
+            .map_err(|_e| ParserError::NumberIsTooLarge { span, found })?;
+
+        let charge = match self.get_next_token_kind()? {
+            Some(TokenKind::MinusSign) => -charge_without_sign,
+            Some(TokenKind::PlusSign) => charge_without_sign,
+            _ => {
+                let (span, found) = self.get_next_token_for_error();
+
+                return Err(ParserError::ChargeSignIsExpected { span, found });
             },
-            None => Ok(1),
+        };
+
+        self.take_token()?;
+
+        Ok(charge)
+    }
+
+    /// Parses a charge in superscript: optional digits followed by the sign.
+    fn parse_superscript_charge(&mut self) -> Result<i8, ParserError> {
+        let token = self.take_token()?;
+        let (span, found) = (token.span, Some(token.text.to_string()));
+        let digits: String = token.text.chars()
+            .filter_map(ChargeStyle::parse_superscript_digit)
+            .map(|x| char::from(b'0' + x))
+            .collect();
+        let charge_without_sign = if digits.is_empty() {
+            1
+        } else {
+            digits.parse::<i8>().map_err(|_e| ParserError::NumberIsTooLarge {
+                span,
+                found: found.clone(),
+            })?
+        };
+
+        match token.text.chars().last() {
+            Some('\u{207b}') => Ok(-charge_without_sign),
+            Some('\u{207a}') => Ok(charge_without_sign),
+            _ => Err(ParserError::ChargeSignIsExpected { span, found }),
         }
     }
 
@@ -80,125 +218,174 @@ impl Parser<'_> {
     fn parse_element(&mut self) -> Result<Element, ParserError> {
         let token = self.take_token()?;
 
-        if RegularExpression::Symbol.get_regex().is_match(&token) {
-            Ok(Element::new(token, self.parse_optional_number()?))
+        if token.kind == TokenKind::Symbol {
+            let count = self.parse_optional_number()?;
+
+            Ok(Element::new(token.text.to_string(), count)
+                .with_span(self.get_span_from(token.span.start)))
         } else {
-            Err(ParserError::ElementIsNotParsed)
+            Err(ParserError::ElementIsNotParsed {
+                span: token.span,
+                found: Some(token.text.to_string()),
+            })
         }
     }
 
+    /// Parses an optional item: an element or a group.
+    fn parse_optional_item(&mut self) -> Result<Option<Item>, ParserError> {
+        Ok(match self.get_next_token_kind()? {
+            Some(TokenKind::OpeningBracket | TokenKind::OpeningSquareBracket) =>
+                Some(self.parse_group()?.into()),
+            Some(TokenKind::Symbol) => Some(self.parse_element()?.into()),
+            _ => None,
+        })
+    }
+
     /// Parses a group.
     fn parse_group(&mut self) -> Result<Group, ParserError> {
-        let start_position = self.position;
-        let mut items: Vec<Box<dyn Item>> = vec![];
+        let start_position = self.get_position();
+        let mut items: Vec<Item> = vec![];
+        let (bracket, closing_bracket_kind) = match self.get_next_token_kind()? {
+            Some(TokenKind::OpeningSquareBracket) =>
+                (Bracket::Square, TokenKind::ClosingSquareBracket),
+            _ => (Bracket::Round, TokenKind::ClosingBracket),
+        };
 
-        self.consume("(")?;
-
-        let regex_for_symbol = RegularExpression::Symbol.get_regex();
+        self.take_token()?;
 
         loop {
-            let next_token = self.get_next_token()?
-                .ok_or(ParserError::ElementGroupOrClosingBracketIsExpected {
-                    start_index: self.position
-                })?;
-
-            if next_token == "(" {
-                items.push(Box::new(self.parse_group()?));
-            } else if regex_for_symbol.is_match(&next_token) {
-                items.push(Box::new(self.parse_element()?));
-            } else if next_token == ")" {
-                self.consume(&next_token)?;
-
-                if items.is_empty() {
-                    return Err(
-                        ParserError::EmptyGroup {
-                            start_index: start_position,
-                            end_index: self.position,
-                        }
-                    );
-                }
+            if let Some(x) = self.parse_optional_item()? {
+                items.push(x);
 
-                break;
-            } else {
-                return Err(
-                    ParserError::ElementGroupOrClosingBracketIsExpected {
-                        start_index: self.position,
+                continue;
+            }
+
+            match self.get_next_token_kind()? {
+                Some(x) if x == closing_bracket_kind => {
+                    let (_, found) = self.get_next_token_for_error();
+
+                    self.consume(closing_bracket_kind)?;
+
+                    if items.is_empty() {
+                        return Err(
+                            ParserError::EmptyGroup {
+                                span: self.get_span_from(start_position),
+                                found,
+                            }
+                        );
                     }
-                );
+
+                    break;
+                },
+                _ => {
+                    let (span, found) = self.get_next_token_for_error();
+
+                    return Err(ParserError::ElementGroupOrClosingBracketIsExpected { span, found });
+                },
             }
         }
 
-        Ok(Group::new(items, self.parse_optional_number()?))
+        let count = self.parse_optional_number()?;
+
+        Ok(Group::new(items, count)
+            .with_bracket(bracket)
+            .with_span(self.get_span_from(start_position)))
+    }
+
+    /// Parses a hydrate part.
+    fn parse_hydrate(&mut self) -> Result<Hydrate, ParserError> {
+        let start_position = self.get_position();
+        let mut items = vec![];
+
+        self.consume(TokenKind::Dot)?;
+
+        let count = self.parse_optional_number()?;
+
+        while let Some(x) = self.parse_optional_item()? {
+            items.push(x);
+        }
+
+        if items.is_empty() {
+            let (span, found) = self.get_next_token_for_error();
+
+            return Err(ParserError::EntityIsExpected { span, found });
+        }
+
+        Ok(Hydrate::new(items, count).with_span(self.get_span_from(start_position)))
     }
 
     /// Parses an entity.
     fn parse_entity(&mut self) -> Result<Entity, ParserError> {
-        let start_position = self.position;
-        let mut items: Vec<Box<dyn Item>> = vec![];
+        let start_position = self.get_position();
+        let mut items: Vec<Item> = vec![];
+        let mut hydrates = vec![];
         let mut is_electron = false;
-        let regex_for_symbol = RegularExpression::Symbol.get_regex();
-        let regex_for_digits = RegularExpression::Digits.get_regex();
-
-        while let Some(x) = self.get_next_token()? {
-            if x == "(" {
-                items.push(Box::new(self.parse_group()?));
-            } else if x == "e" {
-                self.consume(&x)?;
-
-                is_electron = true;
-            } else if regex_for_symbol.is_match(&x) {
-                items.push(Box::new(self.parse_element()?));
-            } else if regex_for_digits.is_match(&x) {
-                return Err(ParserError::NumberIsNotExpected { start_index: self.position });
-            } else {
-                break;
+        let mut charge: Option<i8> = None;
+
+        loop {
+            if let Some(x) = self.parse_optional_item()? {
+                items.push(x);
+
+                continue;
+            }
+
+            match self.get_next_token_kind()? {
+                Some(TokenKind::Electron) => {
+                    self.consume(TokenKind::Electron)?;
+
+                    is_electron = true;
+
+                    if self.get_last_token_end() == self.get_position()
+                        && self.get_next_token_kind()? == Some(TokenKind::MinusSign) {
+                        self.take_token()?;
+                        charge = Some(-1);
+                    }
+                },
+                Some(TokenKind::Number) => {
+                    let (span, found) = self.get_next_token_for_error();
+
+                    return Err(ParserError::NumberIsNotExpected { span, found });
+                },
+                _ => break,
             }
         }
 
-        let mut charge: Option<i8> = None;
+        while !is_electron && self.get_next_token_kind()? == Some(TokenKind::Dot) {
+            hydrates.push(self.parse_hydrate()?);
+        }
 
-        if let Some(x) = self.get_next_token()? {
-            if x == "{" {
-                self.consume(&x)?;
+        if charge.is_none() && self.get_next_token_kind()? == Some(TokenKind::OpeningCurlyBracket) {
+            self.consume(TokenKind::OpeningCurlyBracket)?;
 
-                self.get_next_token()?.ok_or(
-                    ParserError::ChargeOrChargeSignIsExpected { start_index: self.position }
-                )?;
+            if self.get_next_token()?.is_none() {
+                let (span, found) = self.get_next_token_for_error();
 
-                charge = Some(i8::try_from(self.parse_optional_number()?).unwrap());
+                return Err(ParserError::ChargeOrChargeSignIsExpected { span, found });
+            }
 
-                if let Some(x) = self.get_next_token()? {
-                    if x == "-" {
-                        charge = Some(-charge.unwrap());
-                    } else if x != "+" {
-                        return Err(
-                            ParserError::ChargeSignIsExpected { start_index: self.position }
-                        );
-                    }
-                }
+            charge = Some(self.parse_charge()?);
 
-                self.take_token()?;
+            if self.get_next_token_kind()? == Some(TokenKind::ClosingCurlyBracket) {
+                self.consume(TokenKind::ClosingCurlyBracket)?;
+            } else {
+                let (span, found) = self.get_next_token_for_error();
 
-                if let Some(x) = self.get_next_token()? {
-                    if x == "}" {
-                        self.consume(&x)?;
-                    } else {
-                        return Err(
-                            ParserError::ClosingBracketAfterChargeIsExpected {
-                                start_index: self.position,
-                            }
-                        );
-                    }
-                }
+                return Err(ParserError::ClosingBracketAfterChargeIsExpected { span, found });
             }
+        } else if charge.is_none() && self.get_next_token_kind()? == Some(TokenKind::Caret) {
+            self.consume(TokenKind::Caret)?;
+            charge = Some(self.parse_charge()?);
+        } else if charge.is_none()
+            && self.get_next_token_kind()? == Some(TokenKind::SuperscriptCharge) {
+            charge = Some(self.parse_superscript_charge()?);
         }
 
         if is_electron {
             if !items.is_empty() {
                 return Err(
                     ParserError::ElectronNeedsToStandAlone {
-                        start_index: start_position,
-                        end_index: self.position,
+                        span: self.get_span_from(start_position),
+                        found: Some(self.equation[start_position..self.get_last_token_end()].to_string()),
                     }
                 );
             }
@@ -210,19 +397,16 @@ impl Parser<'_> {
             if charge != Some(-1) {
                 return Err(
                     ParserError::InvalidChargeForElectron {
-                        start_index: start_position,
-                        end_index: self.position,
+                        span: self.get_span_from(start_position),
+                        found: Some(self.equation[start_position..self.get_last_token_end()].to_string()),
                     }
                 );
             }
         } else {
             if items.is_empty() {
-                return Err(
-                    ParserError::EntityIsExpected {
-                        start_index: start_position,
-                        end_index: self.position,
-                    }
-                );
+                let (span, found) = self.get_next_token_for_error();
+
+                return Err(ParserError::EntityIsExpected { span, found });
             }
 
             if charge.is_none() {
@@ -230,115 +414,222 @@ impl Parser<'_> {
             }
         }
 
-        Ok(Entity::new(items, charge.unwrap()))
+        let mut phase = None;
+
+        if let Some(token) = self.get_next_token()? {
+            if token.kind == TokenKind::Phase {
+                self.take_token()?;
+
+                phase = Some(Phase::from_symbol(&token.text[1..token.text.len() - 1]).ok_or(
+                    ParserError::UnknownPhase { span: token.span, found: Some(token.text.to_string()) }
+                )?);
+            }
+        }
+
+        Ok(Entity::new(items, charge.unwrap())
+            .with_hydrates(hydrates)
+            .with_phase(phase)
+            .with_span(self.get_span_from(start_position)))
+    }
+
+    /// Parses a single formula, such as `Fe2(SO4)3`, consuming the entire string.
+    pub fn parse_formula(&mut self) -> Result<Entity, ParserError> {
+        let entity = self.parse_entity()?;
+
+        if self.index < self.tokens.len() {
+            let (span, found) = self.get_next_token_for_error();
+
+            return Err(ParserError::EndIsExpected { span, found });
+        }
+
+        Ok(entity)
+    }
+
+    /// Skips the coefficient of a species, a number separated from the formula by a space,
+    /// recording its span.
+    fn skip_coefficient(&mut self) -> Result<(), ParserError> {
+        let is_coefficient = match (self.tokens.get(self.index), self.tokens.get(self.index + 1)) {
+            (Some(number), Some(next_token)) => number.kind == TokenKind::Number
+                && next_token.span.start > number.span.end,
+            _ => false,
+        };
+
+        if is_coefficient {
+            let span = self.tokens[self.index].span;
+            self.consume(TokenKind::Number)?;
+            self.ignored_coefficients.push(span);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the next plus sign is the sign of a charge.
+    fn is_charge_sign(&self) -> bool {
+        self.tokens.get(self.index + 1).map(|x| x.kind) == Some(TokenKind::ClosingCurlyBracket)
+    }
+
+    /// Skips the tokens up to the next plus sign or equal sign separating the entities.
+    fn synchronize(&mut self) {
+        while let Some(x) = self.tokens.get(self.index) {
+            if x.kind == TokenKind::EqualSign
+                || x.kind == TokenKind::PlusSign && !self.is_charge_sign() {
+                break;
+            }
+
+            self.index += 1;
+        }
     }
 
     /// Parses an equation.
     pub fn parse_equation(&mut self) -> Result<Equation, ParserError> {
-        self.skip_spaces();
+        let (equation, mut errors) = self.parse_equation_with_recovery();
+
+        if errors.is_empty() {
+            Ok(equation)
+        } else {
+            Err(errors.remove(0))
+        }
+    }
 
+    /// Parses an equation, recovering from the errors at the plus and equal signs.
+    ///
+    /// Returns the partial equation made of the entities that are parsed and all the errors.
+    pub fn parse_equation_with_recovery(&mut self) -> (Equation, Vec<ParserError>) {
         let mut reactants = vec![];
-        reactants.push(self.parse_entity()?);
+        let mut products = vec![];
+        let mut errors = vec![];
+        let mut is_equal_sign_consumed = false;
 
         loop {
-            let next_token = self.get_next_token()?
-                .ok_or(ParserError::PlusSignOrEqualSignIsExpected { start_index: self.position })?;
+            let entities = if is_equal_sign_consumed { &mut products } else { &mut reactants };
 
-            match next_token.as_str() {
-                "+" => {
-                    self.consume(&next_token)?;
+            match self.skip_coefficient().and_then(|_| self.parse_entity()) {
+                Ok(entity) => entities.push(entity),
+                Err(error) => {
+                    errors.push(error);
+                    self.synchronize();
+                },
+            }
 
-                    reactants.push(self.parse_entity()?);
+            match self.get_next_token_kind() {
+                Ok(None) => break,
+                Ok(Some(TokenKind::PlusSign)) => self.index += 1,
+                Ok(Some(TokenKind::EqualSign)) if !is_equal_sign_consumed => {
+                    self.index += 1;
+                    is_equal_sign_consumed = true;
                 },
-                "=" => {
-                    self.consume(&next_token)?;
+                _ => {
+                    let (span, found) = self.get_next_token_for_error();
 
-                    break;
+                    errors.push(if is_equal_sign_consumed {
+                        ParserError::PlusSignOrEndIsExpected { span, found }
+                    } else {
+                        ParserError::PlusSignOrEqualSignIsExpected { span, found }
+                    });
+
+                    self.synchronize();
+
+                    if self.index == self.tokens.len() {
+                        break;
+                    }
+
+                    self.index += 1;
                 },
-                _ => return Err(
-                    ParserError::PlusSignOrEqualSignIsExpected { start_index: self.position }
-                ),
             }
         }
 
-        let mut products = vec![];
-        products.push(self.parse_entity()?);
-
-        while let Some(x) = self.get_next_token()? {
-            match x.as_str() {
-                "+" => {
-                    self.consume(&x)?;
+        if !is_equal_sign_consumed {
+            let (span, found) = self.get_next_token_for_error();
 
-                    products.push(self.parse_entity()?);
-                }
-                _ => return Err(
-                    ParserError::PlusSignOrEndIsExpected { start_index: self.position }
-                ),
-            }
+            errors.push(ParserError::PlusSignOrEqualSignIsExpected { span, found });
         }
 
-        Ok(Equation::new(reactants, products))
+        (Equation::new(reactants, products), errors)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::Parser;
-    use crate::item::Item;
+    use crate::{
+        bracket::Bracket,
+        item::Item,
+        parser::Parser,
+        phase::Phase,
+        span::Span,
+        token_kind::TokenKind,
+    };
 
     #[test]
     fn test_new() {
         let parser = Parser::new("H2 + O2 = H2O");
         assert_eq!(parser.equation, "H2 + O2 = H2O");
-        assert_eq!(parser.position, 0);
+        assert_eq!(parser.tokens.len(), 9);
+        assert_eq!(parser.index, 0);
     }
 
     #[test]
-    fn test_get_substring() {
-        let parser = Parser::new("H2 + O2 = H2O");
-        assert_eq!(parser.get_substring(), "H2 + O2 = H2O");
+    fn test_parse_equations() {
+        let input = "# Combustion\nH2 + O2 = H2O; C + O2 = CO2\n\n  Fe + = Fe2O3 # Broken\n";
+        let parsed_equations = Parser::parse_equations(input);
+        assert_eq!(parsed_equations.len(), 3);
+        assert_eq!(parsed_equations[0].source, "H2 + O2 = H2O");
+        assert_eq!((parsed_equations[0].line, parsed_equations[0].column), (2, 1));
+        assert_eq!(parsed_equations[1].source, "C + O2 = CO2");
+        assert_eq!((parsed_equations[1].line, parsed_equations[1].column), (2, 16));
+        assert_eq!(parsed_equations[1].offset, 28);
+        assert!(parsed_equations[1].result.is_ok());
+        assert_eq!(parsed_equations[2].source, "Fe + = Fe2O3");
+        assert_eq!((parsed_equations[2].line, parsed_equations[2].column), (4, 3));
+        assert!(parsed_equations[2].result.is_err());
     }
 
     #[test]
-    fn test_get_next_token() {
+    fn test_get_position() {
         let mut parser = Parser::new("H2 + O2 = H2O");
-        assert_eq!(parser.get_next_token().unwrap(), Some("H".to_string()));
+        assert_eq!(parser.get_position(), 0);
+
+        parser.index = 2;
+        assert_eq!(parser.get_position(), 3);
 
-        let _ = parser.consume("H");
-        assert_eq!(parser.get_next_token().unwrap(), Some("2".to_string()));
+        parser.index = parser.tokens.len();
+        assert_eq!(parser.get_position(), 13);
     }
 
     #[test]
-    fn test_take_token() {
+    fn test_get_next_token() {
         let mut parser = Parser::new("H2 + O2 = H2O");
-        assert_eq!(parser.take_token().unwrap(), "H");
-        assert_eq!(parser.position, 1);
-        assert_eq!(parser.take_token().unwrap(), "2");
-        assert_eq!(parser.position, 3);
-        assert_eq!(parser.take_token().unwrap(), "+");
-        assert_eq!(parser.position, 5);
+        assert_eq!(parser.get_next_token().unwrap().unwrap().text, "H");
+
+        let _ = parser.consume(TokenKind::Symbol);
+        assert_eq!(parser.get_next_token().unwrap().unwrap().text, "2");
+
+        let parser = Parser::new("$");
+        assert!(parser.get_next_token().is_err());
     }
 
     #[test]
-    fn test_consume() {
+    fn test_take_token() {
         let mut parser = Parser::new("H2 + O2 = H2O");
-        let _ = parser.consume("H");
-        assert_eq!(parser.position, 1);
+        assert_eq!(parser.take_token().unwrap().text, "H");
+        assert_eq!(parser.get_last_token_end(), 1);
+        assert_eq!(parser.take_token().unwrap().text, "2");
+        assert_eq!(parser.get_last_token_end(), 2);
+        assert_eq!(parser.take_token().unwrap().text, "+");
+        assert_eq!(parser.get_position(), 5);
     }
 
     #[test]
-    fn test_skip_spaces() {
+    fn test_consume() {
         let mut parser = Parser::new("H2 + O2 = H2O");
-        let _ = parser.consume("H");
-        let _ = parser.consume("2");
-        parser.skip_spaces();
-        assert_eq!(parser.position, 3);
+        assert!(parser.consume(TokenKind::Symbol).is_ok());
+        assert_eq!(parser.index, 1);
+        assert!(parser.consume(TokenKind::PlusSign).is_err());
     }
 
     #[test]
     fn test_parse_optional_number() {
         let mut parser = Parser::new("H2 + O2 = H2O");
-        let _ = parser.consume("H");
+        let _ = parser.consume(TokenKind::Symbol);
         assert_eq!(parser.parse_optional_number().unwrap(), 2);
     }
 
@@ -351,15 +642,91 @@ mod tests {
     #[test]
     fn test_parse_group() {
         let mut parser = Parser::new("Al2(SO4)3 = Al2O3 + SO3");
-        let _ = parser.consume("Al");
-        let _ = parser.consume("2");
+        let _ = parser.consume(TokenKind::Symbol);
+        let _ = parser.consume(TokenKind::Number);
         assert_eq!(parser.parse_group().unwrap().format(), "(SO4)3");
     }
 
+    #[test]
+    fn test_parse_hydrate() {
+        let mut parser = Parser::new("*5H2O");
+        let hydrate = parser.parse_hydrate().unwrap();
+        assert_eq!(hydrate.count, 5);
+        assert_eq!(hydrate.format(), "\u{b7}5H2O");
+        assert_eq!(hydrate.span, Span::new(0, 5));
+
+        let mut parser = Parser::new("\u{b7} = H2O");
+        assert!(parser.parse_hydrate().is_err());
+    }
+
     #[test]
     fn test_parse_entity() {
         let mut parser = Parser::new("Al2(SO4)3 = Al2O3 + SO3");
         assert_eq!(parser.parse_entity().unwrap().format(), "Al2(SO4)3");
+
+        let mut parser = Parser::new("[Cu(NH3)4]SO4\u{b7}H2O(s) + H2O(l)");
+        let entity = parser.parse_entity().unwrap();
+        assert_eq!(entity.format(), "[Cu(NH3)4]SO4\u{b7}H2O(s)");
+        assert_eq!(entity.phase, Some(Phase::Solid));
+        assert_eq!(entity.span, Span::new(0, 21));
+        assert_eq!(entity.hydrates.len(), 1);
+
+        let Item::Group(group) = &entity.items[0] else { panic!("The group is expected.") };
+        assert_eq!(group.bracket, Bracket::Square);
+        assert_eq!(group.span, Span::new(0, 10));
+        assert_eq!(group.items[1].get_span(), Span::new(3, 9));
+
+        let mut parser = Parser::new("[Cu(NH3)4)SO4");
+        assert!(parser.parse_entity().is_err());
+
+        let mut parser = Parser::new("NaCl(x)");
+        assert_eq!(parser.parse_entity().err().unwrap().get_description(), "Unknown phase.");
+    }
+
+    #[test]
+    fn test_parse_charge() {
+        let mut parser = Parser::new("2-}");
+        assert_eq!(parser.parse_charge().unwrap(), -2);
+
+        let mut parser = Parser::new("Fe{200+} = Fe");
+        let error = parser.parse_entity().err().unwrap();
+        assert_eq!(error.get_description(), "The number is too large.");
+        assert_eq!(error.get_span(), Span::new(3, 6));
+        assert_eq!(error.get_found(), Some("200"));
+    }
+
+    #[test]
+    fn test_parse_entity_with_charge_styles() {
+        for (formula, charge) in [
+            ("Fe{3+}", 3),
+            ("Fe^3+", 3),
+            ("Fe\u{b3}\u{207a}", 3),
+            ("H\u{207a}", 1),
+            ("SO4^2-", -2),
+            ("e\u{207b}", -1),
+            ("e^-", -1),
+        ] {
+            let mut parser = Parser::new(formula);
+            assert_eq!(parser.parse_entity().unwrap().charge, charge, "{formula}");
+        }
+
+        let mut parser = Parser::new("Fe^3");
+        assert_eq!(
+            parser.parse_entity().err().unwrap().get_description(),
+            "The charge sign is expected."
+        );
+    }
+
+    #[test]
+    fn test_parse_formula() {
+        let mut parser = Parser::new(" Fe2(SO4)3 ");
+        assert_eq!(parser.parse_formula().unwrap().format(), "Fe2(SO4)3");
+
+        let mut parser = Parser::new("Fe2(SO4)3 + H2O");
+        assert_eq!(
+            parser.parse_formula().err().unwrap().get_description(),
+            "The end is expected."
+        );
     }
 
     #[test]
@@ -370,4 +737,51 @@ mod tests {
             "2\u{a0}H2 + O2 = 2\u{a0}H2O"
         );
     }
+
+    #[test]
+    fn test_parse_equation_with_recovery() {
+        let mut parser = Parser::new("Fe2(SO4 + H2O = Fe(OH3 + SO3 + Na2$ + O2");
+        let (equation, errors) = parser.parse_equation_with_recovery();
+        assert_eq!(equation.format(&[]), "H2O = SO3 + O2");
+        assert_eq!(
+            errors.iter().map(|x| x.get_span().start).collect::<Vec<usize>>(),
+            [8, 23, 34]
+        );
+
+        let mut parser = Parser::new("Fe{3+} + e - Fe");
+        let (equation, errors) = parser.parse_equation_with_recovery();
+        assert_eq!(equation.reactants.len(), 2);
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_equation_with_unicode() {
+        let mut parser = Parser::new("Cr2O7{2\u{2212}}\u{a0}+ H{+} + e\u{2212} = Cr{3+} + H2O");
+        let equation = parser.parse_equation().unwrap();
+        assert_eq!(equation.reactants[0].charge, -2);
+        assert_eq!(equation.reactants[2].charge, -1);
+
+        let error = Parser::new("H2 + O2 \u{2192} H2O").parse_equation().err().unwrap();
+        assert_eq!(error.get_span(), Span::new(8, 11));
+        assert_eq!(error.get_found(), Some("\u{2192}"));
+
+        let equation = Parser::new("H2 + O2 = H2O").parse_equation().unwrap();
+        let formatted_equation = equation.format(&[2, 1, 2]);
+        assert_eq!(formatted_equation, "2\u{a0}H2 + O2 = 2\u{a0}H2O");
+        assert_eq!(Parser::new(&formatted_equation).parse_equation().unwrap(), equation);
+
+        let mut parser = Parser::new("2 H2 + O2 = 2 H2O");
+        assert_eq!(parser.parse_equation().unwrap(), equation);
+        assert_eq!(parser.get_ignored_coefficients(), [Span::new(0, 1), Span::new(12, 13)]);
+        assert_eq!(
+            parser.get_warnings()[0],
+            "The coefficient 2 is ignored: the balancer calculates the coefficients."
+        );
+
+        let error = Parser::new("2H2 + O2 = 2 H2O").parse_equation().err().unwrap();
+        assert_eq!(error.get_span(), Span::new(0, 1));
+
+        let (_, errors) = Parser::new("Fe\u{b7}H2O = Fe + \u{a0}").parse_equation_with_recovery();
+        assert!(!errors.is_empty());
+    }
 }
@@ -1,24 +1,103 @@
+use crate::span::Span;
+
 #[derive(Debug)]
 pub enum ParserError {
-    AdvancingBeyondLastToken,
-    ChargeOrChargeSignIsExpected { start_index: usize },
-    ChargeSignIsExpected { start_index: usize },
-    ClosingBracketAfterChargeIsExpected { start_index: usize },
-    ElectronNeedsToStandAlone { start_index: usize, end_index: usize },
-    ElementGroupOrClosingBracketIsExpected { start_index: usize },
-    ElementIsNotParsed,
-    EmptyGroup { start_index: usize, end_index: usize },
-    EntityIsExpected { start_index: usize, end_index: usize },
-    InvalidChargeForElectron { start_index: usize, end_index: usize },
-    InvalidSymbol { start_index: usize },
-    NumberIsNotExpected { start_index: usize },
-    NumberIsTooLarge,
-    PlusSignOrEndIsExpected { start_index: usize },
-    PlusSignOrEqualSignIsExpected { start_index: usize },
-    TokenDoesNotMatchString,
+    AdvancingBeyondLastToken { span: Span, found: Option<String> },
+    ChargeOrChargeSignIsExpected { span: Span, found: Option<String> },
+    ChargeSignIsExpected { span: Span, found: Option<String> },
+    ClosingBracketAfterChargeIsExpected { span: Span, found: Option<String> },
+    ElectronNeedsToStandAlone { span: Span, found: Option<String> },
+    ElementGroupOrClosingBracketIsExpected { span: Span, found: Option<String> },
+    ElementIsNotParsed { span: Span, found: Option<String> },
+    EmptyGroup { span: Span, found: Option<String> },
+    EndIsExpected { span: Span, found: Option<String> },
+    EntityIsExpected { span: Span, found: Option<String> },
+    InvalidChargeForElectron { span: Span, found: Option<String> },
+    InvalidSymbol { span: Span, found: Option<String> },
+    NumberIsNotExpected { span: Span, found: Option<String> },
+    NumberIsTooLarge { span: Span, found: Option<String> },
+    PlusSignOrEndIsExpected { span: Span, found: Option<String> },
+    PlusSignOrEqualSignIsExpected { span: Span, found: Option<String> },
+    TokenDoesNotMatchString { span: Span, found: Option<String>, expected: String },
+    UnknownPhase { span: Span, found: Option<String> },
 }
 
 impl ParserError {
+    /// Returns the span of the error in the input.
+    pub fn get_span(&self) -> Span {
+        match self {
+            Self::AdvancingBeyondLastToken { span, .. }
+            | Self::ChargeOrChargeSignIsExpected { span, .. }
+            | Self::ChargeSignIsExpected { span, .. }
+            | Self::ClosingBracketAfterChargeIsExpected { span, .. }
+            | Self::ElectronNeedsToStandAlone { span, .. }
+            | Self::ElementGroupOrClosingBracketIsExpected { span, .. }
+            | Self::ElementIsNotParsed { span, .. }
+            | Self::EmptyGroup { span, .. }
+            | Self::EndIsExpected { span, .. }
+            | Self::EntityIsExpected { span, .. }
+            | Self::InvalidChargeForElectron { span, .. }
+            | Self::InvalidSymbol { span, .. }
+            | Self::NumberIsNotExpected { span, .. }
+            | Self::NumberIsTooLarge { span, .. }
+            | Self::PlusSignOrEndIsExpected { span, .. }
+            | Self::PlusSignOrEqualSignIsExpected { span, .. }
+            | Self::TokenDoesNotMatchString { span, .. }
+            | Self::UnknownPhase { span, .. } => *span,
+        }
+    }
+
+    /// Returns the token that was found instead of the expected one, or `None` at the end.
+    pub fn get_found(&self) -> Option<&str> {
+        match self {
+            Self::AdvancingBeyondLastToken { found, .. }
+            | Self::ChargeOrChargeSignIsExpected { found, .. }
+            | Self::ChargeSignIsExpected { found, .. }
+            | Self::ClosingBracketAfterChargeIsExpected { found, .. }
+            | Self::ElectronNeedsToStandAlone { found, .. }
+            | Self::ElementGroupOrClosingBracketIsExpected { found, .. }
+            | Self::ElementIsNotParsed { found, .. }
+            | Self::EmptyGroup { found, .. }
+            | Self::EndIsExpected { found, .. }
+            | Self::EntityIsExpected { found, .. }
+            | Self::InvalidChargeForElectron { found, .. }
+            | Self::InvalidSymbol { found, .. }
+            | Self::NumberIsNotExpected { found, .. }
+            | Self::NumberIsTooLarge { found, .. }
+            | Self::PlusSignOrEndIsExpected { found, .. }
+            | Self::PlusSignOrEqualSignIsExpected { found, .. }
+            | Self::TokenDoesNotMatchString { found, .. }
+            | Self::UnknownPhase { found, .. } => found.as_deref(),
+        }
+    }
+
+    /// Returns the descriptions of the expected tokens.
+    pub fn get_expected(&self) -> Vec<String> {
+        let expected: &[&str] = match self {
+            Self::AdvancingBeyondLastToken { .. } => &["a token"],
+            Self::ChargeOrChargeSignIsExpected { .. } => &["a charge", "`+`", "`-`"],
+            Self::ChargeSignIsExpected { .. } => &["`+`", "`-`"],
+            Self::ClosingBracketAfterChargeIsExpected { .. } => &["`}`"],
+            Self::ElectronNeedsToStandAlone { .. } => &["`e` without elements"],
+            Self::ElementGroupOrClosingBracketIsExpected { .. } =>
+                &["an element", "a group", "the closing bracket"],
+            Self::ElementIsNotParsed { .. } => &["an element"],
+            Self::EmptyGroup { .. } => &["an element", "a group"],
+            Self::EndIsExpected { .. } => &["the end"],
+            Self::EntityIsExpected { .. } => &["an entity"],
+            Self::InvalidChargeForElectron { .. } => &["`{-}`"],
+            Self::InvalidSymbol { .. } => &["a valid symbol"],
+            Self::NumberIsNotExpected { .. } => &["an element", "a group", "`e`"],
+            Self::NumberIsTooLarge { .. } => &["a number from 0 to 255"],
+            Self::PlusSignOrEndIsExpected { .. } => &["`+`", "the end"],
+            Self::PlusSignOrEqualSignIsExpected { .. } => &["`+`", "`=`"],
+            Self::TokenDoesNotMatchString { expected, .. } => return vec![format!("`{expected}`")],
+            Self::UnknownPhase { .. } => &["`(s)`", "`(l)`", "`(g)`", "`(aq)`"],
+        };
+
+        expected.iter().map(|x| x.to_string()).collect()
+    }
+
     /// Returns the description
     pub fn get_description(&self) -> &str {
         match self {
@@ -30,17 +109,74 @@ impl ParserError {
             Self::ElectronNeedsToStandAlone { .. } => "An electron needs to stand alone.",
             Self::ElementGroupOrClosingBracketIsExpected { .. } =>
                 "The element, group, or closing bracket is expected.",
-            Self::ElementIsNotParsed => "The element is not parsed.",
+            Self::ElementIsNotParsed { .. } => "The element is not parsed.",
             Self::EmptyGroup { .. } => "Empty group.",
+            Self::EndIsExpected { .. } => "The end is expected.",
             Self::EntityIsExpected { .. } => "The entity is expected.",
             Self::InvalidChargeForElectron { .. } => "Invalid charge for an electron.",
             Self::InvalidSymbol { .. } => "Invalid symbol.",
             Self::NumberIsNotExpected { .. } => "The number is not expected.",
-            Self::NumberIsTooLarge => "The number is too large.",
+            Self::NumberIsTooLarge { .. } => "The number is too large.",
             Self::PlusSignOrEndIsExpected { .. } => "The plus sign or end is expected.",
             Self::PlusSignOrEqualSignIsExpected { .. } =>
                 "The plus sign or equal sign is expected.",
-            Self::TokenDoesNotMatchString => "The token does not match the string.",
+            Self::TokenDoesNotMatchString { .. } => "The token does not match the string.",
+            Self::UnknownPhase { .. } => "Unknown phase.",
         }
     }
+
+    /// Returns the description with a caret line underlining the error in the input.
+    pub fn get_diagnostic(&self, input: &str) -> String {
+        let (start, end) = self.get_span().get_char_range(input);
+        let found = match self.get_found() {
+            Some(x) => format!("`{x}`"),
+            None => "the end".to_string(),
+        };
+
+        [
+            input.to_string(),
+            [" ".repeat(start), "^".repeat((end - start).max(1))].join(""),
+            format!(
+                "{} Expected {}, found {found}.",
+                self.get_description(),
+                self.get_expected().join(" or "),
+            ),
+        ].join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parser::Parser, span::Span};
+
+    #[test]
+    fn test_get_span() {
+        let error = Parser::new("H2 + O2 - H2O").parse_equation().err().unwrap();
+        assert_eq!(error.get_span(), Span::new(8, 9));
+    }
+
+    #[test]
+    fn test_get_found() {
+        let error = Parser::new("H2 + O2 - H2O").parse_equation().err().unwrap();
+        assert_eq!(error.get_found(), Some("-"));
+
+        let error = Parser::new("H2 + O2").parse_equation().err().unwrap();
+        assert_eq!(error.get_found(), None);
+    }
+
+    #[test]
+    fn test_get_expected() {
+        let error = Parser::new("H2 + O2 - H2O").parse_equation().err().unwrap();
+        assert_eq!(error.get_expected(), ["`+`", "`=`"]);
+    }
+
+    #[test]
+    fn test_get_diagnostic() {
+        let error = Parser::new("Fe + (SO4 = X").parse_equation().err().unwrap();
+        assert_eq!(
+            error.get_diagnostic("Fe + (SO4 = X"),
+            "Fe + (SO4 = X\n          ^\nThe element, group, or closing bracket is expected. \
+                Expected an element or a group or the closing bracket, found `=`."
+        );
+    }
 }
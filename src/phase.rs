@@ -0,0 +1,56 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Aqueous,
+    Gas,
+    Liquid,
+    Solid,
+}
+
+impl Phase {
+    /// Returns the phase by its symbol.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "aq" => Some(Self::Aqueous),
+            "g" => Some(Self::Gas),
+            "l" => Some(Self::Liquid),
+            "s" => Some(Self::Solid),
+            _ => None,
+        }
+    }
+
+    /// Returns the symbol.
+    pub fn get_symbol(&self) -> &str {
+        match self {
+            Self::Aqueous => "aq",
+            Self::Gas => "g",
+            Self::Liquid => "l",
+            Self::Solid => "s",
+        }
+    }
+
+    /// Formats a phase.
+    pub fn format(&self) -> String {
+        ["(", self.get_symbol(), ")"].join("")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::phase::Phase;
+
+    #[test]
+    fn test_from_symbol() {
+        assert_eq!(Phase::from_symbol("aq"), Some(Phase::Aqueous));
+        assert_eq!(Phase::from_symbol("x"), None);
+    }
+
+    #[test]
+    fn test_get_symbol() {
+        assert_eq!(Phase::Solid.get_symbol(), "s");
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Phase::Gas.format(), "(g)");
+    }
+}
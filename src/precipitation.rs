@@ -0,0 +1,225 @@
+use itertools::Itertools;
+use crate::{
+    balanced_equation::BalancedEquation,
+    balancer::Balancer,
+    balancer_error::BalancerError,
+    dissociation::Dissociation,
+    electrolyte::Electrolyte,
+    entity::Entity,
+    equation::Equation,
+    phase::Phase,
+    solubility::Solubility,
+};
+
+/// A double-displacement reaction of two aqueous ionic compounds predicted by the solubility
+/// rules.
+#[derive(Debug, Clone)]
+pub struct Precipitation {
+    /// Balanced equation with the phases of the products, or `None` if the compounds share an
+    /// ion or every product stays dissociated, so there is no reaction
+    pub balanced_equation: Option<BalancedEquation>,
+    /// Insoluble products
+    pub precipitates: Vec<Entity>,
+    /// Ions the compounds have in common
+    pub shared_ions: Vec<Entity>,
+}
+
+impl Precipitation {
+    /// Swaps the partners of the ions of the compounds, marks every product as a solid or an
+    /// aqueous solution by the bundled solubility rules and balances the equation.
+    ///
+    /// Insoluble compounds are rejected, and the phases given to the compounds are kept.
+    /// Compounds sharing an ion do not react, nor do compounds whose products are all strong
+    /// electrolytes, as no precipitate, water or weak electrolyte forms.
+    pub fn new(first: &Entity, second: &Entity) -> Result<Self, BalancerError> {
+        let (Some(first_ions), Some(second_ions)) =
+            (Dissociation::new(first), Dissociation::new(second)) else {
+            return Err(BalancerError::IonsAreNotRecognized {
+                names: [first, second].into_iter()
+                    .filter(|x| Dissociation::new(x).is_none())
+                    .map(Entity::format)
+                    .collect(),
+            });
+        };
+        let insoluble_names: Vec<String> = [(first, &first_ions), (second, &second_ions)]
+            .into_iter()
+            .filter(|(_, ions)| !Self::is_soluble(&ions.cation, &ions.anion))
+            .map(|(x, _)| x.format())
+            .collect();
+
+        if !insoluble_names.is_empty() {
+            return Err(BalancerError::ReactantsAreInsoluble { names: insoluble_names });
+        }
+
+        let shared_ions: Vec<Entity> = [
+            (&first_ions.cation, &second_ions.cation),
+            (&first_ions.anion, &second_ions.anion),
+        ].into_iter()
+            .filter(|(x, y)| x == y)
+            .map(|(x, _)| x.clone())
+            .collect();
+
+        if !shared_ions.is_empty() {
+            return Ok(Self { balanced_equation: None, precipitates: vec![], shared_ions });
+        }
+
+        let reactants = [first, second]
+            .map(|x| x.clone().with_phase(x.phase.or(Some(Phase::Aqueous))))
+            .to_vec();
+        let products = [
+            (&first_ions.cation, &second_ions.anion),
+            (&second_ions.cation, &first_ions.anion),
+        ].into_iter()
+            .map(|(cation, anion)| Self::get_product(cation, anion))
+            .collect();
+        let balanced_equation = Balancer::from_equation(Equation::new(reactants, products))
+            .balance()?;
+
+        if balanced_equation.equation.products.iter()
+            .all(|x| Electrolyte::classify(x).is_dissociated()) {
+            return Ok(Self { balanced_equation: None, precipitates: vec![], shared_ions });
+        }
+
+        let precipitates = balanced_equation.equation.products.iter()
+            .filter(|x| x.phase == Some(Phase::Solid))
+            .cloned()
+            .collect();
+
+        Ok(Self { balanced_equation: Some(balanced_equation), precipitates, shared_ions })
+    }
+
+    /// Checks whether the compound of the ions dissolves: acids always do, salts and bases by
+    /// the bundled solubility rules.
+    fn is_soluble(cation: &Entity, anion: &Entity) -> bool {
+        cation == &"H{+}".parse::<Entity>().expect("Ions are valid formulas.")
+            || Solubility::find(cation, anion) == Solubility::Soluble
+    }
+
+    /// Returns the compound of the ions in its phase: water from the neutralization is a
+    /// liquid, an acid is aqueous and a salt is a solid if it is insoluble.
+    fn get_product(cation: &Entity, anion: &Entity) -> Entity {
+        let compound = Dissociation::from_ions(cation.clone(), anion.clone()).get_compound();
+        let water = "H2O".parse::<Entity>().expect("Water is a valid formula.");
+
+        if compound.is_equivalent(&water) {
+            return water.with_phase(Some(Phase::Liquid));
+        }
+
+        let phase = if Self::is_soluble(cation, anion) { Phase::Aqueous } else { Phase::Solid };
+
+        compound.with_phase(Some(phase))
+    }
+
+    /// Checks whether a precipitate forms.
+    pub fn has_precipitate(&self) -> bool {
+        !self.precipitates.is_empty()
+    }
+
+    /// Formats the balanced equation and the precipitates, one per line, or the reason why there
+    /// is no reaction.
+    pub fn format(&self) -> String {
+        let Some(balanced_equation) = &self.balanced_equation else {
+            if self.shared_ions.is_empty() {
+                return "No reaction: every product is soluble and stays dissociated.".to_string();
+            }
+
+            return format!(
+                "No reaction: the compounds share {}.",
+                self.shared_ions.iter().map(Entity::format).join(", "),
+            );
+        };
+        let precipitates = if self.has_precipitate() {
+            format!("Precipitate: {}.", self.precipitates.iter().map(Entity::format).join(", "))
+        } else {
+            "No precipitate forms.".to_string()
+        };
+
+        [balanced_equation.format(), precipitates].join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        balancer_error::BalancerError,
+        entity::Entity,
+        precipitation::Precipitation,
+    };
+
+    fn get_precipitation(first: &str, second: &str) -> Result<Precipitation, BalancerError> {
+        Precipitation::new(&first.parse::<Entity>().unwrap(), &second.parse::<Entity>().unwrap())
+    }
+
+    #[test]
+    fn test_new() {
+        let precipitation = get_precipitation("AgNO3", "NaCl").unwrap();
+        assert_eq!(
+            precipitation.balanced_equation.as_ref().unwrap().format(),
+            "AgNO3(aq) + NaCl(aq) = AgCl(s) + NaNO3(aq)"
+        );
+        assert_eq!(precipitation.precipitates[0].format(), "AgCl(s)");
+
+        let precipitation = get_precipitation("FeCl3", "NaOH").unwrap();
+        assert_eq!(
+            precipitation.balanced_equation.as_ref().unwrap().format(),
+            "FeCl3(aq) + 3\u{a0}NaOH(aq) = Fe(OH)3(s) + 3\u{a0}NaCl(aq)"
+        );
+
+        let precipitation = get_precipitation("Pb(NO3)2", "KI").unwrap();
+        assert_eq!(
+            precipitation.balanced_equation.as_ref().unwrap().format(),
+            "Pb(NO3)2(aq) + 2\u{a0}KI(aq) = PbI2(s) + 2\u{a0}KNO3(aq)"
+        );
+
+        let precipitation = get_precipitation("HCl", "NaOH").unwrap();
+        assert_eq!(
+            precipitation.balanced_equation.as_ref().unwrap().format(),
+            "HCl(aq) + NaOH(aq) = H2O(l) + NaCl(aq)"
+        );
+        assert!(!precipitation.has_precipitate());
+
+        let precipitation = get_precipitation("AgNO3(aq)", "NaCl(s)").unwrap();
+        assert_eq!(
+            precipitation.balanced_equation.as_ref().unwrap().format(),
+            "AgNO3(aq) + NaCl(s) = AgCl(s) + NaNO3(aq)"
+        );
+
+        let precipitation = get_precipitation("NaCl", "KNO3").unwrap();
+        assert!(precipitation.balanced_equation.is_none());
+        assert!(precipitation.shared_ions.is_empty());
+
+        let precipitation = get_precipitation("NaCl", "NaNO3").unwrap();
+        assert!(precipitation.balanced_equation.is_none());
+        assert_eq!(precipitation.shared_ions[0].format(), "Na{+}");
+
+        assert!(matches!(
+            get_precipitation("AgCl", "NaNO3"),
+            Err(BalancerError::ReactantsAreInsoluble { names }) if names == ["AgCl"]
+        ));
+
+        assert!(matches!(
+            get_precipitation("NaCl", "C6H12O6"),
+            Err(BalancerError::IonsAreNotRecognized { names }) if names == ["C6H12O6"]
+        ));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            get_precipitation("BaCl2", "Na2SO4").unwrap().format(),
+            "BaCl2(aq) + Na2SO4(aq) = BaSO4(s) + 2\u{a0}NaCl(aq)\nPrecipitate: BaSO4(s)."
+        );
+        assert_eq!(
+            get_precipitation("NaCl", "KNO3").unwrap().format(),
+            "No reaction: every product is soluble and stays dissociated."
+        );
+        assert_eq!(
+            get_precipitation("HCl", "NaOH").unwrap().format(),
+            "HCl(aq) + NaOH(aq) = H2O(l) + NaCl(aq)\nNo precipitate forms."
+        );
+        assert_eq!(
+            get_precipitation("NaCl", "KCl").unwrap().format(),
+            "No reaction: the compounds share Cl{\u{2212}}."
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use itertools::Itertools;
+use num::{rational::Ratio, Zero};
+use crate::{
+    entity::Entity,
+    equation::Equation,
+    oxidation_change::OxidationChange,
+    oxidation_states::OxidationStates,
+};
+
+/// The changes of the oxidation states in a balanced equation with the agents of the reaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedoxAnalysis {
+    /// Changes of the states, from a reactant to a product
+    pub changes: Vec<OxidationChange>,
+    /// Reactants containing the reduced atoms
+    pub oxidizing_agents: Vec<Entity>,
+    /// Reactants containing the oxidized atoms
+    pub reducing_agents: Vec<Entity>,
+    /// Reactants whose atoms of the same element are both oxidized and reduced, with the
+    /// name of the element
+    pub disproportionations: Vec<(String, Entity)>,
+    /// Products whose atoms of the same element come both from oxidation and reduction,
+    /// with the name of the element
+    pub comproportionations: Vec<(String, Entity)>,
+    /// Names of the elements whose states are not decided in some species
+    pub undetermined_elements_names: Vec<String>,
+}
+
+impl RedoxAnalysis {
+    /// Analyzes the balanced equation with its coefficients.
+    ///
+    /// The atoms of every element are matched from the reactants to the products in order of
+    /// their oxidation states, so that no more electrons are transferred than necessary.
+    pub fn new(equation: &Equation, coefficients: &[i32]) -> Self {
+        let entities: Vec<&Entity> = equation.reactants.iter().chain(&equation.products).collect();
+        let states: Vec<OxidationStates> = entities.iter()
+            .map(|x| x.get_oxidation_states())
+            .collect();
+        let reactants_count = equation.reactants.len();
+        let mut changes: Vec<OxidationChange> = vec![];
+        let mut undetermined_elements_names = vec![];
+
+        for element_name in Self::get_elements_names(&entities) {
+            let mut parts: Vec<(Ratio<i32>, i32, usize)> = vec![];
+            let mut is_determined = true;
+
+            for (i, entity) in entities.iter().enumerate() {
+                let count = entity.get_composition().count_element_by_name(&element_name);
+                let atoms_count = count * coefficients.get(i).copied().unwrap_or(0);
+
+                if atoms_count == 0 {
+                    continue;
+                }
+
+                match states[i].get_state(&element_name) {
+                    Some(x) => parts.push((x, atoms_count, i)),
+                    None => is_determined = false,
+                }
+            }
+
+            if !is_determined {
+                undetermined_elements_names.push(element_name);
+                continue;
+            }
+
+            let (mut reactants_parts, mut products_parts): (Vec<_>, Vec<_>) =
+                parts.into_iter().partition(|&(_, _, i)| i < reactants_count);
+
+            reactants_parts.sort_by_key(|&(x, _, _)| x);
+            products_parts.sort_by_key(|&(x, _, _)| x);
+
+            let (mut i, mut j) = (0, 0);
+
+            while i < reactants_parts.len() && j < products_parts.len() {
+                let (initial_state, reactant_atoms_count, reactant_index) = reactants_parts[i];
+                let (final_state, product_atoms_count, product_index) = products_parts[j];
+                let atoms_count = reactant_atoms_count.min(product_atoms_count);
+
+                if initial_state != final_state {
+                    changes.push(OxidationChange::new(
+                        element_name.clone(),
+                        initial_state,
+                        final_state,
+                        atoms_count,
+                        entities[reactant_index].clone(),
+                        entities[product_index].clone(),
+                    ));
+                }
+
+                reactants_parts[i].1 -= atoms_count;
+                products_parts[j].1 -= atoms_count;
+
+                if reactants_parts[i].1 == 0 {
+                    i += 1;
+                }
+
+                if products_parts[j].1 == 0 {
+                    j += 1;
+                }
+            }
+        }
+
+        let get_reactants = |is_oxidation: bool| changes.iter()
+            .filter(|x| x.is_oxidation() == is_oxidation)
+            .map(|x| x.reactant.clone())
+            .unique()
+            .collect();
+        let oxidizing_agents = get_reactants(false);
+        let reducing_agents = get_reactants(true);
+        let get_proportionations = |get_entity: fn(&OxidationChange) -> &Entity| changes.iter()
+            .filter(|x| x.is_oxidation())
+            .filter(|x| changes.iter().any(|y| !y.is_oxidation()
+                && y.element_name == x.element_name
+                && get_entity(y) == get_entity(x)))
+            .map(|x| (x.element_name.clone(), get_entity(x).clone()))
+            .unique()
+            .collect();
+        let disproportionations = get_proportionations(|x| &x.reactant);
+        let comproportionations = get_proportionations(|x| &x.product);
+
+        Self {
+            changes,
+            oxidizing_agents,
+            reducing_agents,
+            disproportionations,
+            comproportionations,
+            undetermined_elements_names,
+        }
+    }
+
+    /// Returns the names of the elements in order of their first appearance.
+    fn get_elements_names(entities: &[&Entity]) -> Vec<String> {
+        let mut elements_names: Vec<String> = vec![];
+
+        for x in entities {
+            for (element_name, _) in x.get_composition().elements {
+                if !elements_names.contains(&element_name) {
+                    elements_names.push(element_name);
+                }
+            }
+        }
+
+        elements_names
+    }
+
+    /// Checks whether any oxidation state changes.
+    pub fn is_redox(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    /// Returns the number of electrons lost by the oxidized atoms, or gained by the reduced
+    /// atoms in a half-reaction without oxidation.
+    pub fn get_electrons_transferred(&self) -> Ratio<i32> {
+        let get_sum = |is_oxidation: bool| self.changes.iter()
+            .filter(|x| x.is_oxidation() == is_oxidation)
+            .map(OxidationChange::get_electrons_count)
+            .sum::<Ratio<i32>>();
+        let electrons_lost = get_sum(true);
+
+        if electrons_lost.is_zero() { get_sum(false) } else { electrons_lost }
+    }
+
+    /// Formats the analysis, one statement per line.
+    pub fn format(&self) -> String {
+        if !self.is_redox() {
+            return "No oxidation state changes.".to_string();
+        }
+
+        let format_entities = |entities: &[Entity]| entities.iter().map(Entity::format).join(", ");
+        let mut lines: Vec<String> = self.changes.iter().map(|x| x.get_description()).collect();
+
+        lines.push(format!("Electrons transferred: {}.", self.get_electrons_transferred()));
+
+        if !self.oxidizing_agents.is_empty() {
+            lines.push(format!("Oxidizing agent: {}.", format_entities(&self.oxidizing_agents)));
+        }
+
+        if !self.reducing_agents.is_empty() {
+            lines.push(format!("Reducing agent: {}.", format_entities(&self.reducing_agents)));
+        }
+
+        for (element_name, entity) in &self.disproportionations {
+            lines.push(format!(
+                "Disproportionation: {element_name} in {} is both oxidized and reduced.",
+                entity.format(),
+            ));
+        }
+
+        for (element_name, entity) in &self.comproportionations {
+            lines.push(format!(
+                "Comproportionation: {element_name} in {} comes from both oxidation and reduction.",
+                entity.format(),
+            ));
+        }
+
+        if !self.undetermined_elements_names.is_empty() {
+            lines.push(format!(
+                "Undetermined oxidation states: {}.",
+                self.undetermined_elements_names.join(", "),
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::{balancer::Balancer, redox_analysis::RedoxAnalysis};
+
+    fn get_analysis(equation: &str) -> RedoxAnalysis {
+        let balanced_equation = Balancer::new(equation).unwrap().balance().unwrap();
+        RedoxAnalysis::new(&balanced_equation.equation, &balanced_equation.coefficients)
+    }
+
+    #[test]
+    fn test_new() {
+        let analysis = get_analysis("C6H12O6 + KMnO4 + H2SO4 = CO2 + K2SO4 + MnSO4 + H2O");
+        assert_eq!(analysis.changes.len(), 2);
+        assert_eq!(analysis.changes[0].element_name, "C");
+        assert_eq!(analysis.changes[0].atoms_count, 30);
+        assert_eq!(analysis.changes[1].element_name, "Mn");
+        assert_eq!(analysis.oxidizing_agents[0].format(), "KMnO4");
+        assert_eq!(analysis.reducing_agents[0].format(), "C6H12O6");
+        assert!(analysis.disproportionations.is_empty());
+        assert!(analysis.comproportionations.is_empty());
+    }
+
+    #[test]
+    fn test_new_with_disproportionation() {
+        let analysis = get_analysis("Cl2 + NaOH = NaCl + NaClO3 + H2O");
+        assert_eq!(analysis.disproportionations.len(), 1);
+        assert_eq!(analysis.disproportionations[0].0, "Cl");
+        assert_eq!(analysis.disproportionations[0].1.format(), "Cl2");
+        assert_eq!(analysis.get_electrons_transferred(), Ratio::from_integer(5));
+    }
+
+    #[test]
+    fn test_new_with_comproportionation() {
+        let analysis = get_analysis("H2S + SO2 = S + H2O");
+        assert_eq!(analysis.comproportionations.len(), 1);
+        assert_eq!(analysis.comproportionations[0].1.format(), "S");
+        assert_eq!(analysis.get_electrons_transferred(), Ratio::from_integer(4));
+    }
+
+    #[test]
+    fn test_is_redox() {
+        assert!(!get_analysis("NaOH + HCl = NaCl + H2O").is_redox());
+        assert!(get_analysis("Fe{3+} + e = Fe{2+}").is_redox());
+    }
+
+    #[test]
+    fn test_get_electrons_transferred() {
+        let analysis = get_analysis("C6H12O6 + KMnO4 + H2SO4 = CO2 + K2SO4 + MnSO4 + H2O");
+        assert_eq!(analysis.get_electrons_transferred(), Ratio::from_integer(120));
+        assert_eq!(
+            get_analysis("Fe{3+} + e = Fe{2+}").get_electrons_transferred(),
+            Ratio::from_integer(1)
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            get_analysis("H2 + O2 = H2O").format(),
+            "H is oxidized from 0 in H2 to +1 in H2O: 4 atoms lose 4 electrons.\n\
+                O is reduced from 0 in O2 to \u{2212}2 in H2O: 2 atoms gain 4 electrons.\n\
+                Electrons transferred: 4.\n\
+                Oxidizing agent: O2.\n\
+                Reducing agent: H2."
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use itertools::Itertools;
+use crate::entity::Entity;
+
+/// The oxidized and the reduced forms of the species in a half-reaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedoxCouple {
+    /// Species in the higher oxidation state
+    pub oxidized_species: Vec<Entity>,
+    /// Species in the lower oxidation state
+    pub reduced_species: Vec<Entity>,
+}
+
+impl RedoxCouple {
+    /// RedoxCouple constructor.
+    pub fn new(oxidized_species: Vec<Entity>, reduced_species: Vec<Entity>) -> Self {
+        Self { oxidized_species, reduced_species }
+    }
+
+    /// Formats the couple as the oxidized form over the reduced form.
+    pub fn format(&self) -> String {
+        let format_species = |species: &[Entity]| species.iter().map(Entity::format).join(" + ");
+
+        format!(
+            "{}/{}",
+            format_species(&self.oxidized_species),
+            format_species(&self.reduced_species),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::redox_couple::RedoxCouple;
+
+    #[test]
+    fn test_format() {
+        let couple = RedoxCouple::new(
+            vec!["Fe{3+}".parse().unwrap()],
+            vec!["Fe{2+}".parse().unwrap()],
+        );
+        assert_eq!(couple.format(), "Fe{3+}/Fe{2+}");
+    }
+}
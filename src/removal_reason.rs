@@ -0,0 +1,40 @@
+use crate::entity::Entity;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemovalReason {
+    Catalyst,
+    SpectatorIon,
+}
+
+impl RemovalReason {
+    /// Returns the reason of removing the species that appears unchanged on both sides: an
+    /// ion is a spectator and a neutral species is a catalyst.
+    pub fn from_entity(entity: &Entity) -> Self {
+        if entity.charge == 0 { Self::Catalyst } else { Self::SpectatorIon }
+    }
+
+    /// Returns the description.
+    pub fn get_description(&self) -> &str {
+        match self {
+            Self::Catalyst => "The species appears unchanged on both sides, as a catalyst.",
+            Self::SpectatorIon => "The ion appears unchanged on both sides, as a spectator.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::removal_reason::RemovalReason;
+
+    #[test]
+    fn test_from_entity() {
+        assert_eq!(
+            RemovalReason::from_entity(&"MnO2".parse().unwrap()),
+            RemovalReason::Catalyst
+        );
+        assert_eq!(
+            RemovalReason::from_entity(&"Na{+}".parse().unwrap()),
+            RemovalReason::SpectatorIon
+        );
+    }
+}
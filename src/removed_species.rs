@@ -0,0 +1,19 @@
+use crate::{entity::Entity, removal_reason::RemovalReason};
+
+/// A species removed from the equation before balancing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovedSpecies {
+    /// Reactant
+    pub reactant: Entity,
+    /// Product
+    pub product: Entity,
+    /// Reason
+    pub reason: RemovalReason,
+}
+
+impl RemovedSpecies {
+    /// RemovedSpecies constructor.
+    pub fn new(reactant: Entity, product: Entity, reason: RemovalReason) -> Self {
+        Self { reactant, product, reason }
+    }
+}
@@ -0,0 +1,149 @@
+use num::integer::gcd;
+use crate::{balanced_equation::BalancedEquation, entity::Entity, equation::Equation};
+
+/// Species with their coefficients on the two sides of a half-reaction or an equation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sides {
+    /// Reactants with their coefficients
+    pub reactants: Vec<(Entity, i32)>,
+    /// Products with their coefficients
+    pub products: Vec<(Entity, i32)>,
+}
+
+impl Sides {
+    /// Sides constructor.
+    pub fn new(reactants: Vec<(Entity, i32)>, products: Vec<(Entity, i32)>) -> Self {
+        Self { reactants, products }
+    }
+
+    /// Returns the sides of the balanced equation without the species with zero coefficients.
+    pub fn from_balanced_equation(balanced_equation: &BalancedEquation) -> Self {
+        let equation = &balanced_equation.equation;
+        let (reactants_coefficients, products_coefficients) =
+            balanced_equation.coefficients.split_at(equation.reactants.len());
+        let get_side = |entities: &[Entity], coefficients: &[i32]| entities.iter()
+            .cloned()
+            .zip(coefficients.iter().copied())
+            .filter(|(_, x)| *x != 0)
+            .collect();
+
+        Self::new(
+            get_side(&equation.reactants, reactants_coefficients),
+            get_side(&equation.products, products_coefficients),
+        )
+    }
+
+    /// Adds the species to a side, merging it with the same species.
+    pub fn add_species(side: &mut Vec<(Entity, i32)>, entity: &Entity, count: i32) {
+        match side.iter_mut().find(|(x, _)| x == entity) {
+            Some((_, x)) => *x += count,
+            None => side.push((entity.clone(), count)),
+        }
+    }
+
+    /// Adds the species of the other sides to the same sides.
+    pub fn add(&mut self, other: &Self) {
+        for (entity, count) in &other.reactants {
+            Self::add_species(&mut self.reactants, entity, *count);
+        }
+
+        for (entity, count) in &other.products {
+            Self::add_species(&mut self.products, entity, *count);
+        }
+    }
+
+    /// Removes the same species from both sides, as far as their counts allow.
+    pub fn cancel(&mut self) {
+        for (entity, count) in self.reactants.iter_mut() {
+            if let Some((_, other_count)) = self.products.iter_mut().find(|(x, _)| x == entity) {
+                let common_count = (*count).min(*other_count);
+                *count -= common_count;
+                *other_count -= common_count;
+            }
+        }
+
+        self.reactants.retain(|(_, x)| *x != 0);
+        self.products.retain(|(_, x)| *x != 0);
+    }
+
+    /// Returns the sides with the counts multiplied by the factor.
+    pub fn scale(&self, factor: i32) -> Self {
+        let scale_side = |side: &[(Entity, i32)]| side.iter()
+            .map(|(x, count)| (x.clone(), count * factor))
+            .collect();
+
+        Self::new(scale_side(&self.reactants), scale_side(&self.products))
+    }
+
+    /// Divides the counts by their greatest common divisor.
+    pub fn reduce(&mut self) {
+        let divisor = self.reactants.iter()
+            .chain(&self.products)
+            .fold(0, |result, (_, x)| gcd(result, *x));
+
+        if divisor == 0 {
+            return;
+        }
+
+        for (_, count) in self.reactants.iter_mut().chain(self.products.iter_mut()) {
+            *count /= divisor;
+        }
+    }
+
+    /// Returns the balanced equation of the sides.
+    pub fn get_balanced_equation(&self) -> BalancedEquation {
+        let equation = Equation::new(
+            self.reactants.iter().map(|(x, _)| x.clone()).collect(),
+            self.products.iter().map(|(x, _)| x.clone()).collect(),
+        );
+        let coefficients = self.reactants.iter().chain(&self.products).map(|(_, x)| *x).collect();
+
+        BalancedEquation::new(equation, coefficients, vec![], vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{balancer::Balancer, sides::Sides};
+
+    fn get_sides(equation: &str) -> Sides {
+        Sides::from_balanced_equation(&Balancer::new(equation).unwrap().balance().unwrap())
+    }
+
+    #[test]
+    fn test_from_balanced_equation() {
+        let sides = get_sides("H2 + O2 = H2O");
+        assert_eq!(sides.reactants.len(), 2);
+        assert_eq!(sides.products[0].1, 2);
+    }
+
+    #[test]
+    fn test_add_species() {
+        let mut sides = get_sides("H2 + O2 = H2O");
+        Sides::add_species(&mut sides.products, &"H2O".parse().unwrap(), 3);
+        Sides::add_species(&mut sides.products, &"H2O2".parse().unwrap(), 1);
+        assert_eq!(sides.products.len(), 2);
+        assert_eq!(sides.products[0].1, 5);
+    }
+
+    #[test]
+    fn test_cancel() {
+        let get_side = |species: &[(&str, i32)]| species.iter()
+            .map(|&(formula, count)| (formula.parse().unwrap(), count))
+            .collect();
+        let mut sides = Sides::new(
+            get_side(&[("H2O2", 2), ("MnO2", 1)]),
+            get_side(&[("H2O", 2), ("O2", 1), ("MnO2", 2)]),
+        );
+        sides.cancel();
+        assert_eq!(sides.get_balanced_equation().format(), "2\u{a0}H2O2 = 2\u{a0}H2O + O2 + MnO2");
+    }
+
+    #[test]
+    fn test_reduce() {
+        let mut sides = get_sides("H2 + O2 = H2O").scale(3);
+        assert_eq!(sides.get_balanced_equation().format(), "6\u{a0}H2 + 3\u{a0}O2 = 6\u{a0}H2O");
+        sides.reduce();
+        assert_eq!(sides.get_balanced_equation().format(), "2\u{a0}H2 + O2 = 2\u{a0}H2O");
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::OnceLock;
+use crate::entity::Entity;
+
+/// Bundled solubility rules of the ionic compounds.
+const SOLUBILITY_RULES: &str = include_str!("../data/solubility_rules.txt");
+
+/// An ion with the solubility of its compounds and the counter ions that are exceptions.
+type SolubilityRule = (Entity, Solubility, Vec<Entity>);
+
+/// Solubility rules of the bundled table, parsed on the first use.
+static RULES: OnceLock<Vec<SolubilityRule>> = OnceLock::new();
+
+/// The solubility of an ionic compound in water.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Solubility {
+    Insoluble,
+    Soluble,
+}
+
+impl Solubility {
+    /// Returns the solubility by its name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "insoluble" => Some(Self::Insoluble),
+            "soluble" => Some(Self::Soluble),
+            _ => None,
+        }
+    }
+
+    /// Returns the name.
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Insoluble => "insoluble",
+            Self::Soluble => "soluble",
+        }
+    }
+
+    /// Returns the other solubility.
+    pub fn get_opposite(&self) -> Self {
+        match self {
+            Self::Insoluble => Self::Soluble,
+            Self::Soluble => Self::Insoluble,
+        }
+    }
+
+    /// Returns the solubility of the compound of the ions by the first bundled rule of either
+    /// ion, or insoluble if no rule applies.
+    pub fn find(cation: &Entity, anion: &Entity) -> Self {
+        Self::get_rules().iter()
+            .find_map(|(ion, solubility, exceptions)| {
+                let counter_ion = if ion == cation {
+                    anion
+                } else if ion == anion {
+                    cation
+                } else {
+                    return None;
+                };
+
+                Some(if exceptions.contains(counter_ion) {
+                    solubility.get_opposite()
+                } else {
+                    *solubility
+                })
+            })
+            .unwrap_or(Self::Insoluble)
+    }
+
+    /// Returns the rules of the bundled table, in their order.
+    fn get_rules() -> &'static [SolubilityRule] {
+        RULES.get_or_init(|| SOLUBILITY_RULES.lines()
+            .map(str::trim)
+            .filter(|x| !x.is_empty() && !x.starts_with('#'))
+            .map(|x| {
+                let mut fields = x.split(';').map(str::trim);
+                let ion = fields.next().unwrap_or_default()
+                    .parse::<Entity>()
+                    .expect("Ions are valid formulas.");
+                let solubility = fields.next().and_then(Self::from_name)
+                    .expect("Every ion has a solubility.");
+                let exceptions = fields.next().map_or(vec![], |y| y.split(',')
+                    .map(|z| z.trim().parse::<Entity>().expect("Exceptions are valid formulas."))
+                    .collect());
+
+                (ion, solubility, exceptions)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::Entity, solubility::Solubility};
+
+    fn find(cation: &str, anion: &str) -> Solubility {
+        Solubility::find(&cation.parse::<Entity>().unwrap(), &anion.parse::<Entity>().unwrap())
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Solubility::from_name("soluble"), Some(Solubility::Soluble));
+        assert_eq!(Solubility::from_name("slightly"), None);
+    }
+
+    #[test]
+    fn test_get_opposite() {
+        assert_eq!(Solubility::Soluble.get_opposite(), Solubility::Insoluble);
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("Na{+}", "Cl{-}"), Solubility::Soluble);
+        assert_eq!(find("Ag{+}", "Cl{-}"), Solubility::Insoluble);
+        assert_eq!(find("Ba{2+}", "SO4{2-}"), Solubility::Insoluble);
+        assert_eq!(find("Cu{2+}", "SO4{2-}"), Solubility::Soluble);
+        assert_eq!(find("Ba{2+}", "OH{-}"), Solubility::Soluble);
+        assert_eq!(find("Fe{3+}", "OH{-}"), Solubility::Insoluble);
+        assert_eq!(find("K{+}", "PO4{3-}"), Solubility::Soluble);
+        assert_eq!(find("Ca{2+}", "CO3{2-}"), Solubility::Insoluble);
+    }
+}
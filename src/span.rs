@@ -0,0 +1,41 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    /// Start byte offset
+    pub start: usize,
+    /// End byte offset (exclusive)
+    pub end: usize,
+}
+
+impl Span {
+    /// Span constructor.
+    pub fn new(start: usize, end: usize) -> Self {
+        debug_assert!(start <= end, "Invalid span.");
+
+        Self { start, end }
+    }
+
+    /// Returns the character offsets of the span in the input.
+    pub fn get_char_range(&self, input: &str) -> (usize, usize) {
+        let count_chars = |index: usize| input[..index.min(input.len())].chars().count();
+
+        (count_chars(self.start), count_chars(self.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::span::Span;
+
+    #[test]
+    fn test_new() {
+        let span = Span::new(1, 3);
+        assert_eq!(span.start, 1);
+        assert_eq!(span.end, 3);
+    }
+
+    #[test]
+    fn test_get_char_range() {
+        assert_eq!(Span::new(1, 3).get_char_range("H2 + O2"), (1, 3));
+        assert_eq!(Span::new(5, 8).get_char_range("H\u{2212}O + H"), (3, 6));
+    }
+}
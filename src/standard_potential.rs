@@ -0,0 +1,126 @@
+use std::sync::OnceLock;
+use crate::{
+    balanced_equation::BalancedEquation,
+    balancer::Balancer,
+    entity::Entity,
+    medium::Medium,
+    redox_couple::RedoxCouple,
+};
+
+/// Bundled table of the standard reduction potentials.
+const STANDARD_POTENTIALS: &str = include_str!("../data/standard_potentials.txt");
+
+/// Half-reactions of the bundled table, balanced on the first use.
+static TABLE: OnceLock<Vec<StandardPotential>> = OnceLock::new();
+
+/// A reduction half-reaction with its standard potential.
+#[derive(Debug, Clone)]
+pub struct StandardPotential {
+    /// Balanced reduction half-reaction
+    pub half_reaction: BalancedEquation,
+    /// Standard reduction potential in volts
+    pub potential: f64,
+}
+
+impl StandardPotential {
+    /// StandardPotential constructor.
+    pub fn new(half_reaction: BalancedEquation, potential: f64) -> Self {
+        Self { half_reaction, potential }
+    }
+
+    /// Returns the half-reactions of the bundled table, balanced, from the strongest oxidizing
+    /// agent.
+    pub fn get_table() -> &'static [Self] {
+        TABLE.get_or_init(|| STANDARD_POTENTIALS.lines()
+            .map(str::trim)
+            .filter(|x| !x.is_empty() && !x.starts_with('#'))
+            .map(|x| {
+                let (half_reaction, potential) =
+                    x.split_once(';').expect("Every half-reaction has a potential.");
+                let half_reaction = Balancer::new(half_reaction)
+                    .expect("Half-reactions are valid equations.")
+                    .balance()
+                    .expect("Half-reactions balance.");
+
+                Self::new(half_reaction, potential.trim().parse().expect("Potentials are numbers."))
+            })
+            .collect())
+    }
+
+    /// Returns the tabulated half-reaction of the couple in the medium.
+    pub fn find(couple: &RedoxCouple, medium: Medium) -> Option<&'static Self> {
+        Self::get_table().iter().find(|x| x.matches(couple, medium))
+    }
+
+    /// Checks whether the half-reaction reduces the oxidized species of the couple to its
+    /// reduced species, with the ions of the medium.
+    pub fn matches(&self, couple: &RedoxCouple, medium: Medium) -> bool {
+        let [water, hydrogen_ion, hydroxide_ion] = ["H2O", "H{+}", "OH{-}"]
+            .map(|x| x.parse::<Entity>().expect("Medium species are valid formulas."));
+        let equation = &self.half_reaction.equation;
+        let entities: Vec<&Entity> = equation.reactants.iter().chain(&equation.products).collect();
+
+        if entities.contains(&&hydrogen_ion) && medium == Medium::Basic
+            || entities.contains(&&hydroxide_ion) && medium != Medium::Basic {
+            return false;
+        }
+
+        let medium_species = [water, hydrogen_ion, hydroxide_ion];
+        let is_same = |species: &[Entity], couple_species: &[Entity]| {
+            let species: Vec<&Entity> = species.iter()
+                .filter(|x| !x.is_electron() && !medium_species.contains(x))
+                .collect();
+
+            species.len() == couple_species.len()
+                && couple_species.iter().all(|x| species.iter().any(|y| y.is_equivalent(x)))
+        };
+
+        is_same(&equation.reactants, &couple.oxidized_species)
+            && is_same(&equation.products, &couple.reduced_species)
+    }
+
+    /// Returns the number of electrons gained.
+    pub fn get_electrons_count(&self) -> i32 {
+        self.half_reaction.equation.reactants.iter()
+            .position(Entity::is_electron)
+            .map_or(0, |i| self.half_reaction.coefficients[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{medium::Medium, redox_couple::RedoxCouple, standard_potential::StandardPotential};
+
+    fn get_couple(oxidized_species: &str, reduced_species: &str) -> RedoxCouple {
+        RedoxCouple::new(
+            vec![oxidized_species.parse().unwrap()],
+            vec![reduced_species.parse().unwrap()],
+        )
+    }
+
+    #[test]
+    fn test_get_table() {
+        let table = StandardPotential::get_table();
+        assert!(table.len() > 40);
+        assert_eq!(table[0].half_reaction.format(), "F2 + 2\u{a0}e\u{2212} = 2\u{a0}F{\u{2212}}");
+        assert_eq!(table[0].potential, 2.87);
+    }
+
+    #[test]
+    fn test_find() {
+        let potential = StandardPotential::find(&get_couple("Cu{2+}", "Cu"), Medium::Acidic);
+        assert_eq!(potential.unwrap().potential, 0.34);
+
+        let couple = get_couple("MnO4{-}", "MnO2");
+        assert_eq!(StandardPotential::find(&couple, Medium::Acidic).unwrap().potential, 1.68);
+        assert_eq!(StandardPotential::find(&couple, Medium::Basic).unwrap().potential, 0.59);
+
+        assert!(StandardPotential::find(&get_couple("Ti{3+}", "Ti"), Medium::Acidic).is_none());
+    }
+
+    #[test]
+    fn test_get_electrons_count() {
+        let potential = StandardPotential::find(&get_couple("MnO4{-}", "Mn{2+}"), Medium::Acidic);
+        assert_eq!(potential.unwrap().get_electrons_count(), 5);
+    }
+}
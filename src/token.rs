@@ -0,0 +1,31 @@
+use crate::{span::Span, token_kind::TokenKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'eq> {
+    /// Kind
+    pub kind: TokenKind,
+    /// Text
+    pub text: &'eq str,
+    /// Span in the input
+    pub span: Span,
+}
+
+impl Token<'_> {
+    /// Token constructor.
+    pub fn new(kind: TokenKind, text: &str, span: Span) -> Token<'_> {
+        Token { kind, text, span }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{span::Span, token::Token, token_kind::TokenKind};
+
+    #[test]
+    fn test_new() {
+        let token = Token::new(TokenKind::Symbol, "Fe", Span::new(0, 2));
+        assert_eq!(token.kind, TokenKind::Symbol);
+        assert_eq!(token.text, "Fe");
+        assert_eq!(token.span, Span::new(0, 2));
+    }
+}
@@ -0,0 +1,56 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Caret,
+    ClosingBracket,
+    ClosingCurlyBracket,
+    ClosingSquareBracket,
+    Dot,
+    Electron,
+    EqualSign,
+    Invalid,
+    MinusSign,
+    Number,
+    OpeningBracket,
+    OpeningCurlyBracket,
+    OpeningSquareBracket,
+    Phase,
+    PlusSign,
+    SuperscriptCharge,
+    Symbol,
+}
+
+impl TokenKind {
+    /// Returns the text of a token of this kind, if it is fixed.
+    pub fn get_text(&self) -> Option<&str> {
+        match self {
+            Self::Caret => Some("^"),
+            Self::ClosingBracket => Some(")"),
+            Self::ClosingCurlyBracket => Some("}"),
+            Self::ClosingSquareBracket => Some("]"),
+            Self::Electron => Some("e"),
+            Self::EqualSign => Some("="),
+            Self::MinusSign => Some("-"),
+            Self::OpeningBracket => Some("("),
+            Self::OpeningCurlyBracket => Some("{"),
+            Self::OpeningSquareBracket => Some("["),
+            Self::PlusSign => Some("+"),
+            Self::Dot
+            | Self::Invalid
+            | Self::Number
+            | Self::Phase
+            | Self::SuperscriptCharge
+            | Self::Symbol => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::token_kind::TokenKind;
+
+    #[test]
+    fn test_get_text() {
+        assert_eq!(TokenKind::OpeningBracket.get_text(), Some("("));
+        assert_eq!(TokenKind::Symbol.get_text(), None);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{element::Element, entity::Entity, group::Group, hydrate::Hydrate, item::Item};
+
+/// Walks the formula tree. Every method visits the children by default.
+pub trait Visitor {
+    /// Visits an entity.
+    fn visit_entity(&mut self, entity: &Entity) {
+        walk_entity(self, entity);
+    }
+
+    /// Visits an item.
+    fn visit_item(&mut self, item: &Item) {
+        walk_item(self, item);
+    }
+
+    /// Visits an element.
+    fn visit_element(&mut self, _element: &Element) {}
+
+    /// Visits a group.
+    fn visit_group(&mut self, group: &Group) {
+        walk_group(self, group);
+    }
+
+    /// Visits a hydrate part.
+    fn visit_hydrate(&mut self, hydrate: &Hydrate) {
+        walk_hydrate(self, hydrate);
+    }
+}
+
+/// Visits the items and hydrate parts of an entity.
+pub fn walk_entity<V: Visitor + ?Sized>(visitor: &mut V, entity: &Entity) {
+    for x in &entity.items {
+        visitor.visit_item(x);
+    }
+
+    for x in &entity.hydrates {
+        visitor.visit_hydrate(x);
+    }
+}
+
+/// Visits the element or group of an item.
+pub fn walk_item<V: Visitor + ?Sized>(visitor: &mut V, item: &Item) {
+    match item {
+        Item::Element(x) => visitor.visit_element(x),
+        Item::Group(x) => visitor.visit_group(x),
+    }
+}
+
+/// Visits the items of a group.
+pub fn walk_group<V: Visitor + ?Sized>(visitor: &mut V, group: &Group) {
+    for x in &group.items {
+        visitor.visit_item(x);
+    }
+}
+
+/// Visits the items of a hydrate part.
+pub fn walk_hydrate<V: Visitor + ?Sized>(visitor: &mut V, hydrate: &Hydrate) {
+    for x in &hydrate.items {
+        visitor.visit_item(x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        element::Element,
+        group::Group,
+        parser::Parser,
+        visitor::{Visitor, walk_group},
+    };
+
+    struct Collector {
+        symbols: Vec<String>,
+        groups_count: usize,
+    }
+
+    impl Visitor for Collector {
+        fn visit_element(&mut self, element: &Element) {
+            self.symbols.push(element.name.clone());
+        }
+
+        fn visit_group(&mut self, group: &Group) {
+            self.groups_count += 1;
+            walk_group(self, group);
+        }
+    }
+
+    #[test]
+    fn test_walk_entity() {
+        let equation = Parser::new("[Cu(NH3)4]SO4\u{b7}H2O = Cu").parse_equation().unwrap();
+        let mut collector = Collector { symbols: vec![], groups_count: 0 };
+        equation.reactants[0].accept(&mut collector);
+        assert_eq!(collector.symbols, ["Cu", "N", "H", "S", "O", "H", "O"]);
+        assert_eq!(collector.groups_count, 2);
+    }
+}